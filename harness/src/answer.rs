@@ -0,0 +1,175 @@
+use num::{BigInt, ToPrimitive};
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle. Numeric answers compare by value no matter which variant
+/// holds them, so `Unsigned(5) == Signed(5)`.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    /// For answers that don't fit in 64 bits.
+    Big(BigInt),
+    Text(String),
+    /// A part that hasn't been solved yet. Runners show it as pending and leave it out of the
+    /// timings. It is never equal to a real answer.
+    Unimplemented,
+}
+
+impl Answer {
+    pub fn is_implemented(&self) -> bool {
+        !matches!(self, Answer::Unimplemented)
+    }
+
+    fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Answer::Unsigned(v) => Some(BigInt::from(*v)),
+            Answer::Signed(v) => Some(BigInt::from(*v)),
+            Answer::Big(v) => Some(v.clone()),
+            Answer::Text(_) | Answer::Unimplemented => None,
+        }
+    }
+
+    /// Uses the smallest variant that holds `value`.
+    fn from_bigint(value: BigInt) -> Self {
+        if let Some(v) = value.to_u64() {
+            Answer::Unsigned(v)
+        } else if let Some(v) = value.to_i64() {
+            Answer::Signed(v)
+        } else {
+            Answer::Big(value)
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Unimplemented, Answer::Unimplemented) => true,
+            (Answer::Text(_) | Answer::Unimplemented, _)
+            | (_, Answer::Text(_) | Answer::Unimplemented) => false,
+            _ => self.partial_cmp(other) == Some(Ordering::Equal),
+        }
+    }
+}
+
+/// Numeric answers are ordered by value, so they can be checked against "too high" and "too low"
+/// bounds. Text only compares with text.
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => Some(a.cmp(b)),
+            (Answer::Signed(a), Answer::Signed(b)) => Some(a.cmp(b)),
+            (Answer::Text(a), Answer::Text(b)) => Some(a.cmp(b)),
+            (Answer::Unimplemented, Answer::Unimplemented) => Some(Ordering::Equal),
+            (Answer::Text(_) | Answer::Unimplemented, _)
+            | (_, Answer::Text(_) | Answer::Unimplemented) => None,
+            _ => Some(self.to_bigint()?.cmp(&other.to_bigint()?)),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Unsigned(v) => write!(f, "{v}"),
+            Answer::Signed(v) => write!(f, "{v}"),
+            Answer::Big(v) => write!(f, "{v}"),
+            Answer::Text(v) => write!(f, "{v}"),
+            Answer::Unimplemented => write!(f, "pending"),
+        }
+    }
+}
+
+/// Reads back a printed answer, e.g. from another revision's output. Anything that isn't an
+/// integer is kept as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<BigInt>() {
+            Ok(value) => Answer::from_bigint(value),
+            Err(_) => Answer::Text(s.to_string()),
+        })
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_across_variants() {
+        assert_eq!(Answer::Unsigned(5), Answer::Signed(5));
+        assert_eq!(Answer::Big(BigInt::from(5)), Answer::Unsigned(5));
+        assert!(Answer::Signed(-1) < Answer::Unsigned(0));
+        assert!(Answer::Unsigned(u64::MAX) < Answer::Big(BigInt::from(u64::MAX) + 1));
+        assert_ne!(Answer::from("5"), Answer::Unsigned(5));
+        assert_eq!(Answer::from("5").partial_cmp(&Answer::Unsigned(5)), None);
+    }
+
+    #[test]
+    fn test_unimplemented() {
+        assert_ne!(Answer::Unimplemented, Answer::Unsigned(0));
+        assert_ne!(Answer::Unimplemented, Answer::from("pending"));
+        assert_eq!(
+            Answer::Unimplemented.partial_cmp(&Answer::Unsigned(0)),
+            None
+        );
+        assert!(!Answer::Unimplemented.is_implemented());
+        assert!(Answer::Signed(0).is_implemented());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!("55488".parse(), Ok(Answer::Unsigned(55488))));
+        assert!(matches!("-948".parse(), Ok(Answer::Signed(-948))));
+        assert!(matches!("99999999999999999999".parse(), Ok(Answer::Big(_))));
+        assert!(matches!("EZPCHZ".parse(), Ok(Answer::Text(_))));
+    }
+}
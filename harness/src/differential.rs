@@ -0,0 +1,32 @@
+use crate::generator::Rng;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Runs `solution` and `reference` on `cases` inputs for every size from 1 to `max_size`, and
+/// panics with the smallest input they disagree on. A panic in either counts as a disagreement.
+pub fn assert_agree<T: PartialEq + Debug>(
+    max_size: usize,
+    cases: u64,
+    generate: impl Fn(&mut Rng, usize) -> String,
+    solution: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+) {
+    let run = |f: &dyn Fn(&str) -> T, input: &str| {
+        panic::catch_unwind(AssertUnwindSafe(|| f(input))).map_err(|_| "panicked")
+    };
+    for size in 1..=max_size {
+        let mut smallest: Option<(String, String)> = None;
+        for case in 0..cases {
+            let input = generate(&mut Rng::new(size as u64 * cases + case), size);
+            let (actual, expected) = (run(&solution, &input), run(&reference, &input));
+            let agree = matches!((&actual, &expected), (Ok(a), Ok(e)) if a == e);
+            if !agree && smallest.as_ref().is_none_or(|s| input.len() < s.0.len()) {
+                let answers = format!("solution: {actual:?}, reference: {expected:?}");
+                smallest = Some((input, answers));
+            }
+        }
+        if let Some((input, answers)) = smallest {
+            panic!("Disagreement at size {size} ({answers}) on input:\n{input}");
+        }
+    }
+}
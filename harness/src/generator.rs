@@ -0,0 +1,45 @@
+use crate::Day;
+
+pub type GenerateFn = fn(&mut Rng, usize) -> String;
+
+/// Small xorshift generator, so the same seed always generates the same input.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // Zero is a fixed point of xorshift.
+        Self(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `low..=high`.
+    pub fn range(&mut self, low: usize, high: usize) -> usize {
+        assert!(low <= high, "Empty range {low}..={high}");
+        low + (self.next_u64() % (high - low + 1) as u64) as usize
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.range(0, items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0, i));
+        }
+    }
+}
+
+/// Prints an input for `day`. The size is roughly the number of lines or the side of a grid.
+pub fn print(day: &Day, seed: u64, size: usize) -> Result<(), String> {
+    let generate = day
+        .generate
+        .ok_or_else(|| format!("Day {} has no generator.", day.day))?;
+    println!("{}", generate(&mut Rng::new(seed), size));
+    Ok(())
+}
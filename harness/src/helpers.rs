@@ -0,0 +1,131 @@
+use fxhash::FxHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Mixed into every hash of the maps and sets below. Changing it changes their iteration order,
+/// which `check-determinism` uses to find solvers that depend on it. FxHash itself has no seed,
+/// so without this every run would iterate in the same order.
+static HASH_SEED: AtomicUsize = AtomicUsize::new(0);
+
+/// Applies to the maps and sets created from now on.
+pub fn set_hash_seed(seed: usize) {
+    HASH_SEED.store(seed, Ordering::Relaxed);
+}
+
+/// FxHash, started from the hash seed current when the map was created. The default seed of 0
+/// hashes exactly like plain FxHash.
+#[derive(Clone)]
+pub struct SeededFxBuildHasher(usize);
+
+impl Default for SeededFxBuildHasher {
+    fn default() -> Self {
+        Self(HASH_SEED.load(Ordering::Relaxed))
+    }
+}
+
+impl BuildHasher for SeededFxBuildHasher {
+    type Hasher = FxHasher;
+
+    fn build_hasher(&self) -> FxHasher {
+        let mut hasher = FxHasher::default();
+        hasher.write_usize(self.0);
+        hasher
+    }
+}
+
+/// The solvers use these instead of the `fxhash` ones, so `check-determinism` can reorder them.
+pub type FxHashMap<K, V> = HashMap<K, V, SeededFxBuildHasher>;
+pub type FxHashSet<T> = HashSet<T, SeededFxBuildHasher>;
+
+pub fn map_with_capacity<K, V>(capacity: usize) -> FxHashMap<K, V> {
+    FxHashMap::with_capacity_and_hasher(capacity, SeededFxBuildHasher::default())
+}
+
+pub fn new_map<K, V>() -> FxHashMap<K, V> {
+    map_with_capacity(0)
+}
+
+/// Cleans up the things that editors and browsers add to a saved input: a UTF-8 byte order mark,
+/// Windows line endings, spaces at the end of lines and blank lines at the end of the file. The
+/// result has no trailing newline, which is what the parsers expect.
+pub fn normalize_input(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = String::with_capacity(input.len());
+    for line in input.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Collects the keys of a day 3 sized grid in iteration order. The seed is passed to the
+    /// hasher directly, as the global one is shared with the tests running alongside.
+    fn grid_order(seed: usize) -> Vec<(isize, isize)> {
+        let mut map = FxHashMap::with_capacity_and_hasher(5000, SeededFxBuildHasher(seed));
+        for y in 0..140 {
+            for x in 0..140 {
+                map.insert((y, x), ());
+            }
+        }
+        map.into_keys().collect()
+    }
+
+    #[test]
+    fn test_hash_seed_changes_order() {
+        assert_eq!(grid_order(0), grid_order(0));
+        assert_ne!(grid_order(0), grid_order(1));
+        assert_ne!(grid_order(1), grid_order(2));
+    }
+
+    #[test]
+    fn test_seed_zero_is_plain_fxhash() {
+        let mut plain = fxhash::FxHashSet::default();
+        let mut seeded = FxHashSet::with_hasher(SeededFxBuildHasher(0));
+        for word in ["seed", "soil", "fertilizer", "water", "light"] {
+            plain.insert(word);
+            seeded.insert(word);
+        }
+        assert!(plain.iter().eq(seeded.iter()));
+    }
+
+    #[test]
+    fn test_crlf() {
+        assert_eq!(
+            normalize_input("seeds: 79 14\r\n\r\nsoil:\r\n"),
+            "seeds: 79 14\n\nsoil:"
+        );
+    }
+
+    #[test]
+    fn test_bom() {
+        assert_eq!(normalize_input("\u{feff}rn=1,cm-"), "rn=1,cm-");
+    }
+
+    #[test]
+    fn test_trailing_blank_lines() {
+        assert_eq!(normalize_input("rn=1,cm-\n"), "rn=1,cm-");
+        assert_eq!(
+            normalize_input("px{a<2006:qkq,rfg}\n\n\n"),
+            "px{a<2006:qkq,rfg}"
+        );
+    }
+
+    #[test]
+    fn test_stray_spaces() {
+        assert_eq!(
+            normalize_input("32T3K 765 \nT55J5 684\t\n  \n"),
+            "32T3K 765\nT55J5 684"
+        );
+    }
+
+    #[test]
+    fn test_blank_lines_between_sections_are_kept() {
+        assert_eq!(normalize_input("a\n\nb\n"), "a\n\nb");
+    }
+}
@@ -0,0 +1,199 @@
+pub mod answer;
+pub mod differential;
+pub mod generator;
+pub mod helpers;
+pub mod plugins;
+pub mod repl;
+pub mod trace;
+pub mod validate;
+pub mod variants;
+pub mod vault;
+
+use answer::Answer;
+use colored::*;
+use generator::GenerateFn;
+use repl::ReplFn;
+use std::io;
+use std::time::Duration;
+use trace::Trace;
+use validate::ValidateFn;
+use variants::Variant;
+
+pub type DayResult = (Option<Duration>, (Answer, Duration), (Answer, Duration));
+/// The parse time and one part's answer, see `Day::run_part`.
+pub type PartResult = (Option<Duration>, (Answer, Duration));
+
+/// A solved day as known to the runner.
+pub struct Day {
+    pub year: u16,
+    pub day: usize,
+    pub run: fn(&str, &Trace) -> DayResult,
+    /// Solves a single part, for callers that only want one, like `aoc_solve`.
+    pub run_part: Option<fn(&str, usize, &Trace) -> PartResult>,
+    pub color: Color,
+    /// Days that are still in progress are listed, but skipped when running everything.
+    pub enabled: bool,
+    /// Interactive queries against the parsed input, see `aoc repl`.
+    pub repl: Option<ReplFn>,
+    /// Skips `normalize_input` for puzzles where whitespace is part of the input.
+    pub raw_input: bool,
+    /// Solvers that use rayon, which `--compare-threads` runs with different pool sizes.
+    pub parallel: bool,
+    /// Alternative implementations of the parts, cross-checked by `--variants`.
+    pub variants: &'static [Variant],
+    /// Makes up inputs of a given size, see `aoc generate`.
+    pub generate: Option<GenerateFn>,
+    /// Checks the structure of an input without solving it, see `aoc check-input`.
+    pub validate: Option<ValidateFn>,
+}
+
+impl Day {
+    pub const fn new(
+        year: u16,
+        day: usize,
+        run: fn(&str, &Trace) -> DayResult,
+        color: Color,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            run,
+            run_part: None,
+            color,
+            enabled: true,
+            repl: None,
+            raw_input: false,
+            parallel: false,
+            variants: &[],
+            generate: None,
+            validate: None,
+        }
+    }
+
+    pub const fn with_run_part(self, run_part: fn(&str, usize, &Trace) -> PartResult) -> Self {
+        Self {
+            run_part: Some(run_part),
+            ..self
+        }
+    }
+
+    /// Runs only `part`, through `run_part` if the day has one.
+    pub fn solve_part(&self, input: &str, part: usize, trace: &Trace) -> PartResult {
+        match self.run_part {
+            Some(run_part) => run_part(input, part, trace),
+            None => {
+                let (parse, p1, p2) = (self.run)(input, trace);
+                (parse, if part == 1 { p1 } else { p2 })
+            }
+        }
+    }
+
+    pub const fn with_repl(self, repl: ReplFn) -> Self {
+        Self {
+            repl: Some(repl),
+            ..self
+        }
+    }
+
+    pub const fn disabled(self) -> Self {
+        Self {
+            enabled: false,
+            ..self
+        }
+    }
+
+    pub const fn with_variants(self, variants: &'static [Variant]) -> Self {
+        Self { variants, ..self }
+    }
+
+    pub const fn with_generator(self, generate: GenerateFn) -> Self {
+        Self {
+            generate: Some(generate),
+            ..self
+        }
+    }
+
+    pub const fn with_validator(self, validate: ValidateFn) -> Self {
+        Self {
+            validate: Some(validate),
+            ..self
+        }
+    }
+
+    pub const fn parallel(self) -> Self {
+        Self {
+            parallel: true,
+            ..self
+        }
+    }
+
+    /// Hands the input to the day as it is in the file, for puzzles where whitespace matters.
+    pub const fn raw_input(self) -> Self {
+        Self {
+            raw_input: true,
+            ..self
+        }
+    }
+
+    /// Normalizes the line endings and trailing whitespace of an input, unless the day wants it
+    /// raw. Everything that hands input to a day goes through here.
+    pub fn prepare_input(&self, contents: String) -> String {
+        if self.raw_input {
+            contents
+        } else {
+            helpers::normalize_input(&contents)
+        }
+    }
+}
+
+/// One event's days, registered with the runner as `aoc <year> ...`.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+/// Reads a day's puzzle input from `input/<year>/`, or the example from the puzzle text if
+/// `example` is set. Either may also come sealed from the vault. Unless the day asks for its raw
+/// input, line endings and trailing whitespace are normalized.
+pub fn load_input(day: &Day, example: bool) -> io::Result<String> {
+    let suffix = if example { "_example" } else { "" };
+    let contents = vault::read_input(&format!("{}/day_{:0>2}{suffix}.txt", day.year, day.day))?;
+    Ok(day.prepare_input(contents))
+}
+
+/// Sums the parse and part timings of a result. Unimplemented parts don't count.
+pub fn total_duration(result: &DayResult) -> Duration {
+    let (parse_duration, p1, p2) = result;
+    let mut total_duration = parse_duration.unwrap_or_default();
+    for (answer, duration) in [p1, p2] {
+        if answer.is_implemented() {
+            total_duration += *duration;
+        }
+    }
+    total_duration
+}
+
+/// Time spent on a part, which is nothing for parts that haven't been implemented.
+pub fn part_duration(answer: &Answer, duration: Duration) -> Option<Duration> {
+    answer.is_implemented().then_some(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use colored::Color::Red;
+
+    fn run(_: &str, _: &Trace) -> DayResult {
+        let part = (Answer::Unimplemented, Duration::ZERO);
+        (None, part.clone(), part)
+    }
+
+    #[test]
+    fn test_prepare_input() {
+        let input = "\u{feff}#.#  \r\n .# \r\n\r\n";
+        let day = Day::new(2023, 1, run, Red);
+        assert_eq!(day.prepare_input(input.to_string()), "#.#\n .#");
+        let day = Day::new(2023, 1, run, Red).raw_input();
+        assert_eq!(day.prepare_input(input.to_string()), input);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::answer::Answer;
+use crate::DayResult;
+use colored::*;
+use libloading::Library;
+use std::ffi::{c_char, CStr};
+use std::path::Path;
+use std::time::Duration;
+use std::{env, fs};
+
+pub const ABI_VERSION: u32 = 2;
+
+/// Shared libraries in here are loaded as plugins, so an experimental solver can be tried without
+/// rebuilding the runner.
+const DIR: &str = "plugins";
+
+#[repr(C)]
+pub struct RawPart {
+    pub answer: *mut c_char,
+    pub nanos: u64,
+}
+
+#[repr(C)]
+pub struct RawResult {
+    pub parse_nanos: u64,
+    pub part_1: RawPart,
+    pub part_2: RawPart,
+}
+
+/// What a plugin's `const struct RawPlugin *aoc_plugin(void)` returns, living as long as the
+/// library. `abi_version` must equal `ABI_VERSION`, which is bumped by any change to these structs.
+/// Answers are nul-terminated strings owned by the plugin and handed back through `free`, with
+/// null for a part that isn't solved. Timings are in nanoseconds, and a `parse_nanos` of
+/// `u64::MAX` means the plugin doesn't parse separately.
+#[repr(C)]
+pub struct RawPlugin {
+    pub abi_version: u32,
+    pub year: u32,
+    pub day: u32,
+    pub name: *const c_char,
+    pub solve: extern "C" fn(input: *const u8, len: usize) -> RawResult,
+    pub free: extern "C" fn(result: RawResult),
+}
+
+/// A loaded plugin. The function pointers are only valid while `_library` is loaded.
+pub struct Plugin {
+    pub year: u16,
+    pub day: usize,
+    pub name: String,
+    solve: extern "C" fn(*const u8, usize) -> RawResult,
+    free: extern "C" fn(RawResult),
+    _library: Library,
+}
+
+impl Plugin {
+    pub fn run(&self, input: &str) -> DayResult {
+        let raw = (self.solve)(input.as_ptr(), input.len());
+        // SAFETY: the ABI promises the answers are null or nul-terminated until `free`.
+        let result = unsafe { convert(&raw) };
+        (self.free)(raw);
+        result
+    }
+}
+
+/// # Safety
+/// Both answers must be null or point to nul-terminated strings.
+unsafe fn convert(raw: &RawResult) -> DayResult {
+    let part = |part: &RawPart| {
+        let answer = if part.answer.is_null() {
+            Answer::Unimplemented
+        } else {
+            let text = CStr::from_ptr(part.answer).to_string_lossy();
+            text.parse().unwrap_or_else(|e| match e {})
+        };
+        (answer, Duration::from_nanos(part.nanos))
+    };
+    let parse = (raw.parse_nanos != u64::MAX).then(|| Duration::from_nanos(raw.parse_nanos));
+    (parse, part(&raw.part_1), part(&raw.part_2))
+}
+
+/// Checks what `aoc_plugin()` returned before anything else in it is used, and reads the name.
+///
+/// # Safety
+/// `raw` must be null or point to a `RawPlugin`, whose `name` is null or nul-terminated.
+unsafe fn check(raw: *const RawPlugin) -> Result<String, String> {
+    if raw.is_null() {
+        return Err(String::from("aoc_plugin returned null"));
+    }
+    if (*raw).abi_version != ABI_VERSION {
+        return Err(format!(
+            "ABI version {}, expected {ABI_VERSION}",
+            (*raw).abi_version
+        ));
+    }
+    if (*raw).name.is_null() {
+        return Err(String::from("null name"));
+    }
+    Ok(CStr::from_ptr((*raw).name).to_string_lossy().into_owned())
+}
+
+fn load(path: &Path) -> Result<Plugin, String> {
+    // SAFETY: loading runs the library's initializers; plugins are trusted like the runner itself.
+    let library = unsafe { Library::new(path) }.map_err(|e| e.to_string())?;
+    // SAFETY: the symbol's signature is fixed by the ABI, and `check` looks at the version before
+    // anything else in the struct is read.
+    let (raw, name) = unsafe {
+        let entry = library
+            .get::<extern "C" fn() -> *const RawPlugin>(b"aoc_plugin\0")
+            .map_err(|e| e.to_string())?;
+        let raw = entry();
+        let name = check(raw)?;
+        (&*raw, name)
+    };
+    Ok(Plugin {
+        year: raw.year as u16,
+        day: raw.day as usize,
+        name,
+        solve: raw.solve,
+        free: raw.free,
+        _library: library,
+    })
+}
+
+/// Loads every shared library in `plugins/`, warning about the ones that can't be used.
+pub fn load_all() -> Vec<Plugin> {
+    let Ok(entries) = fs::read_dir(DIR) else {
+        return Vec::new();
+    };
+    let mut paths = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.extension().and_then(|e| e.to_str()) == Some(env::consts::DLL_EXTENSION)
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|path| match load(path) {
+            Ok(plugin) => Some(plugin),
+            Err(e) => {
+                let warning = format!("⚠ Skipping plugin {}: {e}", path.display());
+                eprintln!("{}", warning.yellow());
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::process::Command;
+    use std::ptr;
+
+    #[test]
+    fn test_convert() {
+        let answer = CString::new("-948").unwrap();
+        let raw = RawResult {
+            parse_nanos: u64::MAX,
+            part_1: RawPart {
+                answer: answer.as_ptr() as *mut c_char,
+                nanos: 1500,
+            },
+            part_2: RawPart {
+                answer: ptr::null_mut(),
+                nanos: 0,
+            },
+        };
+        let (parse, (p1, p1_duration), (p2, _)) = unsafe { convert(&raw) };
+        assert_eq!(parse, None);
+        assert!(matches!(p1, Answer::Signed(-948)));
+        assert_eq!(p1_duration, Duration::from_nanos(1500));
+        assert!(!p2.is_implemented());
+    }
+
+    extern "C" fn solve_nothing(_: *const u8, _: usize) -> RawResult {
+        RawResult {
+            parse_nanos: u64::MAX,
+            part_1: RawPart {
+                answer: ptr::null_mut(),
+                nanos: 0,
+            },
+            part_2: RawPart {
+                answer: ptr::null_mut(),
+                nanos: 0,
+            },
+        }
+    }
+
+    extern "C" fn free_nothing(_: RawResult) {}
+
+    #[test]
+    fn test_check() {
+        let mut raw = RawPlugin {
+            abi_version: ABI_VERSION,
+            year: 2023,
+            day: 6,
+            name: c"nothing".as_ptr(),
+            solve: solve_nothing,
+            free: free_nothing,
+        };
+        assert_eq!(unsafe { check(&raw) }, Ok(String::from("nothing")));
+        raw.name = ptr::null();
+        assert_eq!(unsafe { check(&raw) }, Err(String::from("null name")));
+        raw.abi_version = ABI_VERSION - 1;
+        assert!(unsafe { check(&raw) }.is_err());
+        assert!(unsafe { check(ptr::null()) }.is_err());
+    }
+
+    /// Builds the example plugin in `plugins/example` and runs it the way the runner does.
+    #[test]
+    fn test_example_plugin() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let target = root.join("target/example-plugin");
+        let status = Command::new(env!("CARGO"))
+            .arg("build")
+            .arg("--manifest-path")
+            .arg(root.join("plugins/example/Cargo.toml"))
+            .arg("--target-dir")
+            .arg(&target)
+            .status()
+            .expect("Failed to run cargo.");
+        assert!(status.success(), "The example plugin didn't build.");
+        let file = format!(
+            "{}aoc_example_plugin.{}",
+            env::consts::DLL_PREFIX,
+            env::consts::DLL_EXTENSION
+        );
+
+        let plugin = load(&target.join("debug").join(file)).unwrap();
+        assert_eq!((plugin.year, plugin.day), (2023, 6));
+        assert_eq!(plugin.name, "brute-force");
+        let (parse, (p1, _), (p2, _)) = plugin.run("Time:      7  15   30\nDistance:  9  40  200");
+        assert_eq!(parse, None);
+        assert!(matches!(p1, Answer::Unsigned(288)));
+        assert!(matches!(p2, Answer::Unsigned(71503)));
+        assert!(!plugin.run("Time: x\nDistance: 9").1 .0.is_implemented());
+    }
+}
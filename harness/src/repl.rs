@@ -0,0 +1,67 @@
+use colored::*;
+use std::io::{self, BufRead, Write};
+
+/// A command understood by a day's REPL session.
+pub struct Command {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub help: &'static str,
+}
+
+/// Parsed puzzle state that can be queried interactively. Implemented by the days themselves so
+/// commands can call straight into their private types.
+pub trait Session {
+    fn commands(&self) -> &'static [Command];
+
+    /// Runs `command` with the whitespace separated `args` and returns the text to print.
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+pub type ReplFn = for<'a> fn(&'a str) -> Box<dyn Session + 'a>;
+
+fn print_help(session: &dyn Session) {
+    for command in session.commands() {
+        println!("  {:<28} {}", command.usage.bold(), command.help);
+    }
+    println!("  {:<28} Shows this list", "help".bold());
+    println!("  {:<28} Leaves the REPL", "quit".bold());
+}
+
+pub fn run(day: usize, session: &mut dyn Session) -> io::Result<()> {
+    println!("Loaded day {day}. Type `help` for a list of commands.");
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("{}", format!("day{day:0>2}> ").bright_green());
+        io::stdout().flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            println!();
+            return Ok(());
+        };
+        let mut words = line.split_ascii_whitespace();
+        let Some(command) = words.next() else {
+            continue;
+        };
+        let args = words.collect::<Vec<_>>();
+        match command {
+            "quit" | "exit" => return Ok(()),
+            "help" => print_help(session),
+            _ if !session.commands().iter().any(|c| c.name == command) => {
+                println!("{}", format!("Unknown command `{command}`").red())
+            }
+            _ => match session.execute(command, &args) {
+                Ok(output) => println!("{output}"),
+                Err(e) => println!("{}", e.red()),
+            },
+        }
+    }
+}
+
+/// Parses a single numeric argument for commands such as `seed 79`.
+pub fn parse_arg<T: std::str::FromStr>(args: &[&str], index: usize) -> Result<T, String> {
+    let arg = args
+        .get(index)
+        .ok_or_else(|| format!("Missing argument {}", index + 1))?;
+    arg.parse::<T>()
+        .map_err(|_| format!("Couldn't understand `{arg}`"))
+}
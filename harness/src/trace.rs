@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// Sink for solvers to explain their reasoning when run with `--explain`. Off by default, in which
+/// case `explain!` skips even formatting its message.
+#[derive(Debug, Default)]
+pub struct Trace {
+    enabled: bool,
+}
+
+impl Trace {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    #[inline(always)]
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn log(&self, message: fmt::Arguments) {
+        println!("  {message}");
+    }
+}
+
+/// Logs a formatted line to a `Trace`, e.g. `explain!(trace, "{line} → {value}")`.
+#[macro_export]
+macro_rules! explain {
+    ($trace:expr, $($arg:tt)*) => {
+        if $trace.enabled() {
+            $trace.log(format_args!($($arg)*));
+        }
+    };
+}
+
+pub use crate::explain;
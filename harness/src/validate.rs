@@ -0,0 +1,106 @@
+use std::fmt;
+
+/// Checks an input against the day's grammar without solving it, see `aoc check-input`.
+pub type ValidateFn = fn(&str) -> Vec<Violation>;
+
+/// Something wrong with an input, at a 1-based line and column.
+#[derive(Debug, PartialEq)]
+pub struct Violation {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// The whitespace separated fields of `text` with the 0-based column each starts at.
+pub fn fields(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(' ')
+        .scan(0, |column, field| {
+            let start = *column;
+            *column += field.len() + 1;
+            Some((start, field))
+        })
+        .filter(|(_, field)| !field.is_empty())
+}
+
+/// Checks that every field of `text`, which starts at `column` of `line`, is a number.
+pub fn check_numbers(line: usize, column: usize, text: &str, signed: bool) -> Vec<Violation> {
+    fields(text)
+        .filter(|(_, field)| {
+            let digits = if signed {
+                field.strip_prefix('-').unwrap_or(field)
+            } else {
+                field
+            };
+            digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit())
+        })
+        .map(|(start, field)| {
+            Violation::new(line, column + start, format!("`{field}` isn't a number"))
+        })
+        .collect()
+}
+
+/// Checks that `input` is a non-empty grid of equally long rows made of `allowed` characters.
+pub fn check_grid(input: &str, allowed: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let Some(width) = input.lines().next().map(str::len) else {
+        return vec![Violation::new(1, 1, "the grid is empty")];
+    };
+    for (y, row) in input.lines().enumerate() {
+        if row.len() != width {
+            let message = format!("row is {} wide, but the first row is {width}", row.len());
+            violations.push(Violation::new(y + 1, row.len().min(width) + 1, message));
+        }
+        for (x, c) in row.chars().enumerate() {
+            if !allowed.contains(c) {
+                violations.push(Violation::new(y + 1, x + 1, format!("unexpected `{c}`")));
+            }
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_numbers() {
+        assert_eq!(check_numbers(1, 8, "79 14  55 13", false), []);
+        assert_eq!(
+            check_numbers(2, 1, "1 -2 x3", false),
+            [
+                Violation::new(2, 3, "`-2` isn't a number"),
+                Violation::new(2, 6, "`x3` isn't a number"),
+            ]
+        );
+        assert_eq!(check_numbers(1, 1, "0 -2", true), []);
+    }
+
+    #[test]
+    fn test_check_grid() {
+        assert_eq!(check_grid("..#\n#..", ".#"), []);
+        assert_eq!(
+            check_grid("..#\n#.\n.x.", ".#"),
+            [
+                Violation::new(2, 3, "row is 2 wide, but the first row is 3"),
+                Violation::new(3, 2, "unexpected `x`"),
+            ]
+        );
+    }
+}
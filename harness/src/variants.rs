@@ -0,0 +1,91 @@
+use crate::answer::Answer;
+use crate::plugins::Plugin;
+use crate::trace::Trace;
+use crate::{load_input, Day};
+use colored::*;
+use std::iter;
+use std::time::{Duration, Instant};
+
+/// One of several implementations of a part. Variants parse the input themselves, so their
+/// timings include parsing.
+pub struct Variant {
+    pub part: usize,
+    pub name: &'static str,
+    pub solve: fn(&str, &Trace) -> Answer,
+}
+
+impl Variant {
+    pub const fn new(part: usize, name: &'static str, solve: fn(&str, &Trace) -> Answer) -> Self {
+        Self { part, name, solve }
+    }
+}
+
+/// Runs the built-in solution and every variant and plugin of a day, and prints their answers and
+/// timings. Returns whether the solved answers of each part agreed.
+fn check_day(day: &Day, plugins: &[&Plugin], trace: &Trace) -> bool {
+    let contents = load_input(day, false).expect("File not found.");
+    println!("{}", format!("🎄Day {} variants 🎄", day.day).bold());
+
+    let mut results: Vec<(usize, String, Answer, Duration)> = Vec::new();
+    let runs = iter::once((String::from("built-in"), (day.run)(&contents, trace)))
+        .chain(plugins.iter().map(|p| (p.name.clone(), p.run(&contents))));
+    for (name, (parse, p1, p2)) in runs {
+        for (part, (answer, duration)) in [(1, p1), (2, p2)] {
+            let duration = duration + parse.unwrap_or_default();
+            results.push((part, name.clone(), answer, duration));
+        }
+    }
+    for variant in day.variants {
+        let start = Instant::now();
+        let answer = (variant.solve)(&contents, trace);
+        results.push((
+            variant.part,
+            variant.name.to_string(),
+            answer,
+            start.elapsed(),
+        ));
+    }
+    let width = results.iter().map(|r| r.1.len()).max().unwrap_or(0);
+
+    let mut agree = true;
+    for part in [1, 2] {
+        let answers = results.iter().filter(|r| r.0 == part).collect::<Vec<_>>();
+        for (_, name, answer, duration) in &answers {
+            println!(
+                "Part {part} {name:<width$}: {} ({duration:?})",
+                answer.to_string().bold()
+            );
+        }
+        let mut solved = answers.iter().filter(|r| r.2.is_implemented());
+        let Some((_, first_name, first, _)) = solved.next() else {
+            continue;
+        };
+        for (_, name, answer, _) in solved {
+            if answer != first {
+                agree = false;
+                let warning = format!(
+                    "⚠ Day {} part {part}: `{name}` gave {answer} but `{first_name}` gave {first}",
+                    day.day
+                );
+                println!("{}", warning.bold().red());
+            }
+        }
+    }
+    println!();
+    agree
+}
+
+/// Runs the variants and plugins of all the given days that have any.
+pub fn check(days: &[Day], plugins: &[Plugin], trace: &Trace) -> bool {
+    let mut agree = true;
+    for day in days {
+        let plugins = plugins
+            .iter()
+            .filter(|p| p.year == day.year && p.day == day.day)
+            .collect::<Vec<_>>();
+        if !day.variants.is_empty() || !plugins.is_empty() {
+            agree &= check_day(day, &plugins, trace);
+        }
+    }
+    agree
+}
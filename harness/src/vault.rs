@@ -0,0 +1,258 @@
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::{env, fs, io};
+
+const INPUT_DIR: &str = "input";
+const VAULT_DIR: &str = "vault";
+const EXTENSION: &str = "sealed";
+/// Shared by every file, so the passphrase only goes through Argon2 once per run.
+const SALT_FILE: &str = "vault/salt";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Where the vault's key comes from, in the order `from_env` looks for them.
+pub enum Secret {
+    /// 32 bytes in hex, from `AOC_VAULT_KEY`.
+    Key(String),
+    /// From `AOC_VAULT_PASSPHRASE`, or typed in by `aoc vault`.
+    Passphrase(String),
+}
+
+impl Secret {
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_VAULT_KEY")
+            .map(Secret::Key)
+            .or_else(|_| env::var("AOC_VAULT_PASSPHRASE").map(Secret::Passphrase))
+            .ok()
+    }
+
+    /// Turns the secret into a key. A passphrase is stretched with the vault's salt, which is
+    /// created first if `create` is set and the vault doesn't have one yet.
+    pub fn key(&self, create: bool) -> io::Result<Key> {
+        match self {
+            Secret::Key(hex) => Key::from_hex(hex),
+            Secret::Passphrase(passphrase) => {
+                let salt = match fs::read(SALT_FILE) {
+                    Err(e) if e.kind() == io::ErrorKind::NotFound && create => {
+                        let mut salt = vec![0; SALT_LEN];
+                        OsRng.fill_bytes(&mut salt);
+                        fs::create_dir_all(VAULT_DIR)?;
+                        fs::write(SALT_FILE, &salt)?;
+                        salt
+                    }
+                    salt => salt?,
+                };
+                Key::derive(passphrase, &salt)
+            }
+        }
+    }
+}
+
+pub struct Key(ChaCha20Poly1305);
+
+impl Key {
+    fn from_hex(hex: &str) -> io::Result<Self> {
+        let invalid = || io::Error::other("AOC_VAULT_KEY should be 64 hex digits.");
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let bytes = (0..32)
+            .map(|i| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid()))
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Key(
+            ChaCha20Poly1305::new_from_slice(&bytes).map_err(|_| invalid())?
+        ))
+    }
+
+    fn derive(passphrase: &str, salt: &[u8]) -> io::Result<Self> {
+        let mut bytes = [0; 32];
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), salt, &mut bytes)
+            .map_err(|e| io::Error::other(format!("Failed to derive the key: {e}")))?;
+        Ok(Key(ChaCha20Poly1305::new(&bytes.into())))
+    }
+
+    /// Prefixes the ciphertext with the random nonce it was sealed with. The input's `name` is
+    /// authenticated along with it, so a sealed file doesn't open under another name.
+    fn encrypt(&self, name: &Path, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let aad = associated_data(name);
+        let ciphertext = self
+            .0
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad: &aad,
+                },
+            )
+            .expect("Encryption failed.");
+        [nonce.as_slice(), &ciphertext].concat()
+    }
+
+    fn decrypt(&self, name: &Path, sealed: &[u8]) -> io::Result<Vec<u8>> {
+        let wrong_key = || {
+            io::Error::other(format!(
+                "The vault key is wrong, or {} is corrupted.",
+                sealed_path(name).display()
+            ))
+        };
+        if sealed.len() < NONCE_LEN {
+            return Err(wrong_key());
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let aad = associated_data(name);
+        self.0
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| wrong_key())
+    }
+}
+
+/// The name relative to `input/`, with `/` between components on every platform.
+fn associated_data(name: &Path) -> Vec<u8> {
+    let components = name
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>();
+    components.join("/").into_bytes()
+}
+
+fn sealed_path(name: &Path) -> PathBuf {
+    let mut path = Path::new(VAULT_DIR).join(name).into_os_string();
+    path.push(format!(".{EXTENSION}"));
+    PathBuf::from(path)
+}
+
+/// The files below `dir` with the given extension, relative to it.
+fn files_with_extension(dir: &Path, extension: &str) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+    while let Some(sub_dir) = pending.pop() {
+        let entries = match fs::read_dir(dir.join(&sub_dir)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            entries => entries?,
+        };
+        for entry in entries {
+            let name = sub_dir.join(entry?.file_name());
+            let path = dir.join(&name);
+            if path.is_dir() {
+                pending.push(name);
+            } else if path.extension().is_some_and(|e| e == extension) {
+                files.push(name);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Encrypts every `input/**/*.txt` into `vault/**/*.txt.sealed`. Files whose sealed copy is
+/// already up to date are left alone, so resealing doesn't change them in git. Returns how many
+/// were written.
+pub fn seal(key: &Key) -> io::Result<usize> {
+    // The whole vault is opened before anything is written, so a mistyped passphrase can't seal
+    // part of it under another key.
+    let mut current = BTreeMap::new();
+    for sealed in files_with_extension(Path::new(VAULT_DIR), EXTENSION)? {
+        let name = sealed.with_extension("");
+        let plaintext = key.decrypt(&name, &fs::read(Path::new(VAULT_DIR).join(&sealed))?)?;
+        current.insert(name, plaintext);
+    }
+    let mut changed = Vec::new();
+    for name in files_with_extension(Path::new(INPUT_DIR), "txt")? {
+        let plaintext = fs::read(Path::new(INPUT_DIR).join(&name))?;
+        if current.get(&name) != Some(&plaintext) {
+            changed.push((name, plaintext));
+        }
+    }
+    for (name, plaintext) in &changed {
+        let path = sealed_path(name);
+        fs::create_dir_all(path.parent().expect("Sealed files are in the vault."))?;
+        fs::write(&path, key.encrypt(name, plaintext))?;
+        println!("Sealed {}", path.display());
+    }
+    Ok(changed.len())
+}
+
+/// Decrypts the whole vault back into `input/`, overwriting what is there. Returns how many
+/// files were written.
+pub fn open(key: &Key) -> io::Result<usize> {
+    let sealed = files_with_extension(Path::new(VAULT_DIR), EXTENSION)?;
+    for sealed_name in &sealed {
+        let name = sealed_name.with_extension("");
+        let plaintext = key.decrypt(&name, &fs::read(Path::new(VAULT_DIR).join(sealed_name))?)?;
+        let path = Path::new(INPUT_DIR).join(name);
+        fs::create_dir_all(path.parent().expect("Inputs are in the input directory."))?;
+        fs::write(&path, plaintext)?;
+        println!("Opened {}", path.display());
+    }
+    Ok(sealed.len())
+}
+
+/// The key from the environment, derived at most once per run.
+fn env_key() -> Option<&'static io::Result<Key>> {
+    static KEY: OnceLock<Option<io::Result<Key>>> = OnceLock::new();
+    KEY.get_or_init(|| Secret::from_env().map(|secret| secret.key(false)))
+        .as_ref()
+}
+
+/// Reads `input/<name>`, or decrypts it in memory from the vault when only the sealed copy is
+/// there and the key is in the environment.
+pub fn read_input(name: &str) -> io::Result<String> {
+    let error = match fs::read_to_string(Path::new(INPUT_DIR).join(name)) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => e,
+        contents => return contents,
+    };
+    let Ok(sealed) = fs::read(sealed_path(Path::new(name))) else {
+        return Err(error);
+    };
+    let key = match env_key() {
+        Some(Ok(key)) => key,
+        Some(Err(e)) => return Err(io::Error::new(e.kind(), e.to_string())),
+        None => {
+            return Err(io::Error::other(format!(
+                "{INPUT_DIR}/{name} is sealed, set AOC_VAULT_PASSPHRASE or AOC_VAULT_KEY"
+            )))
+        }
+    };
+    String::from_utf8(key.decrypt(Path::new(name), &sealed)?).map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::from_hex(&"2a".repeat(32)).unwrap();
+        let name = Path::new("2023/day_01.txt");
+        let sealed = key.encrypt(name, b"1abc2\npqr3stu8vwx\n");
+        assert_ne!(key.encrypt(name, b"1abc2\npqr3stu8vwx\n"), sealed);
+        assert_eq!(key.decrypt(name, &sealed).unwrap(), b"1abc2\npqr3stu8vwx\n");
+
+        let other = Key::from_hex(&"2b".repeat(32)).unwrap();
+        assert!(other.decrypt(name, &sealed).is_err());
+        assert!(key.decrypt(name, &sealed[..8]).is_err());
+        // Swapping sealed files around doesn't go unnoticed.
+        assert!(key.decrypt(Path::new("2023/day_02.txt"), &sealed).is_err());
+    }
+
+    #[test]
+    fn test_from_hex() {
+        assert!(Key::from_hex(&"0f".repeat(32)).is_ok());
+        assert!(Key::from_hex(&"0f".repeat(31)).is_err());
+        assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+    }
+}
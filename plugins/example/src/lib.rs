@@ -0,0 +1,122 @@
+//! A plugin solving 2023 day 6 by trying every hold time, to show what `plugins/` expects. The
+//! structs mirror `harness/src/plugins.rs`, and any change there bumps `abi_version`.
+
+use std::ffi::{c_char, CString};
+use std::time::Instant;
+
+#[repr(C)]
+pub struct RawPart {
+    answer: *mut c_char,
+    nanos: u64,
+}
+
+#[repr(C)]
+pub struct RawResult {
+    parse_nanos: u64,
+    part_1: RawPart,
+    part_2: RawPart,
+}
+
+#[repr(C)]
+pub struct RawPlugin {
+    abi_version: u32,
+    year: u32,
+    day: u32,
+    name: *const c_char,
+    solve: extern "C" fn(input: *const u8, len: usize) -> RawResult,
+    free: extern "C" fn(result: RawResult),
+}
+
+/// The name points to a static string, so sharing the struct between threads is fine.
+struct Static(RawPlugin);
+
+unsafe impl Sync for Static {}
+
+static PLUGIN: Static = Static(RawPlugin {
+    abi_version: 2,
+    year: 2023,
+    day: 6,
+    name: c"brute-force".as_ptr(),
+    solve,
+    free,
+});
+
+#[no_mangle]
+pub extern "C" fn aoc_plugin() -> *const RawPlugin {
+    &PLUGIN.0
+}
+
+/// The numbers after the label of a line, or `None` if any of them isn't one.
+fn numbers(line: Option<&str>) -> Option<Vec<u64>> {
+    line?
+        .split_whitespace()
+        .skip(1)
+        .map(|n| n.parse().ok())
+        .collect()
+}
+
+fn wins(time: u64, distance: u64) -> u64 {
+    (0..=time)
+        .filter(|hold| hold * (time - hold) > distance)
+        .count() as u64
+}
+
+fn part_1(times: &[u64], distances: &[u64]) -> Option<u64> {
+    if times.len() != distances.len() {
+        return None;
+    }
+    Some(
+        times
+            .iter()
+            .zip(distances)
+            .map(|(&t, &d)| wins(t, d))
+            .product(),
+    )
+}
+
+fn part_2(times: &[u64], distances: &[u64]) -> Option<u64> {
+    let join = |numbers: &[u64]| {
+        numbers
+            .iter()
+            .map(u64::to_string)
+            .collect::<String>()
+            .parse()
+            .ok()
+    };
+    Some(wins(join(times)?, join(distances)?))
+}
+
+/// Times `solve`, handing its answer over as a string the runner gives back through `free`.
+fn part(solve: impl FnOnce() -> Option<u64>) -> RawPart {
+    let start = Instant::now();
+    let answer = solve();
+    let nanos = start.elapsed().as_nanos() as u64;
+    let answer = match answer {
+        Some(answer) => CString::new(answer.to_string()).unwrap().into_raw(),
+        None => std::ptr::null_mut(),
+    };
+    RawPart { answer, nanos }
+}
+
+extern "C" fn solve(input: *const u8, len: usize) -> RawResult {
+    // SAFETY: the runner passes its input as a `&str`, valid for the duration of the call.
+    let input = unsafe { std::str::from_utf8_unchecked(std::slice::from_raw_parts(input, len)) };
+    let mut lines = input.lines();
+    let (times, distances) = (numbers(lines.next()), numbers(lines.next()));
+    let (times, distances) = (times.unwrap_or_default(), distances.unwrap_or_default());
+    let solved = !times.is_empty();
+    RawResult {
+        parse_nanos: u64::MAX,
+        part_1: part(|| part_1(&times, &distances).filter(|_| solved)),
+        part_2: part(|| part_2(&times, &distances).filter(|_| solved)),
+    }
+}
+
+extern "C" fn free(result: RawResult) {
+    for answer in [result.part_1.answer, result.part_2.answer] {
+        if !answer.is_null() {
+            // SAFETY: non-null answers come from `CString::into_raw` in `part`.
+            drop(unsafe { CString::from_raw(answer) });
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::answer::Answer;
+use crate::generator::Rng;
+use crate::trace::Trace;
+use crate::vault;
+use crate::{load_input, total_duration, Day, DayResult, Year};
+use colored::*;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+const CELL_WIDTH: usize = 16;
+
+/// Answers the site accepted, keyed by day and part.
+type KnownAnswers = BTreeMap<(usize, usize), Answer>;
+
+/// Reads accepted answers, one `<day> <part> <answer>` per line, e.g. `5 1 35`. They belong to
+/// the puzzle input, so they sit next to it in `input/<year>/answers.txt`.
+fn parse_answers(input: &str) -> KnownAnswers {
+    input
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ' ');
+            let day = fields.next()?.parse().ok()?;
+            let part = fields.next()?.parse().ok()?;
+            let Ok(answer) = fields.next()?.trim().parse();
+            Some(((day, part), answer))
+        })
+        .collect()
+}
+
+enum Status {
+    /// Ran on the real input, so the runtime and answers mean something.
+    Measured(DayResult),
+    /// Only the example or a generated input was at hand, which still tells which parts are done.
+    Sampled(DayResult),
+    Failed,
+}
+
+fn status(day: &Day) -> Status {
+    let run = |input: &str| {
+        panic::catch_unwind(AssertUnwindSafe(|| (day.run)(input, &Trace::default()))).ok()
+    };
+    if let Ok(input) = load_input(day, false) {
+        return run(&input).map_or(Status::Failed, Status::Measured);
+    }
+    let sample = load_input(day, true)
+        .ok()
+        .or_else(|| Some(day.generate?(&mut Rng::new(0), 10)));
+    match sample.and_then(|input| run(&input)) {
+        Some(result) => Status::Sampled(result),
+        None => Status::Failed,
+    }
+}
+
+fn format_short(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1e6;
+    if micros < 1e3 {
+        format!("{micros:.0}µs")
+    } else if micros < 1e6 {
+        format!("{:.1}ms", micros / 1e3)
+    } else {
+        format!("{:.2}s", micros / 1e6)
+    }
+}
+
+fn pad(text: String) -> String {
+    format!("{text:<CELL_WIDTH$}")
+}
+
+/// The two lines of a day's cell: its number and a star per solved part, then whether each
+/// answer matches the accepted one and the runtime on the real input.
+fn cell(number: usize, day: Option<&Day>, known: &KnownAnswers) -> [ColoredString; 2] {
+    let Some(day) = day else {
+        return [
+            pad(format!("{number:>2}")).dimmed(),
+            pad(String::new()).normal(),
+        ];
+    };
+    let (result, measured) = match status(day) {
+        Status::Measured(result) => (result, true),
+        Status::Sampled(result) => (result, false),
+        Status::Failed => {
+            return [
+                pad(format!("{number:>2} ✗✗")).red(),
+                pad(String::from("   failed")).red(),
+            ];
+        }
+    };
+    let (_, (p1, _), (p2, _)) = &result;
+    let stars = [p1, p2]
+        .iter()
+        .map(|answer| {
+            if answer.is_implemented() {
+                '★'
+            } else {
+                '☆'
+            }
+        })
+        .collect::<String>();
+    let verified = [(1, p1), (2, p2)]
+        .iter()
+        .map(|(part, answer)| match known.get(&(number, *part)) {
+            Some(known) if measured && known == *answer => '✓',
+            Some(_) if measured && answer.is_implemented() => '✗',
+            _ => '·',
+        })
+        .collect::<String>();
+    let runtime = if measured {
+        format_short(total_duration(&result))
+    } else {
+        String::from("no input")
+    };
+
+    let top = pad(format!("{number:>2} {stars}"));
+    let top = if !day.enabled {
+        top.yellow()
+    } else if p1.is_implemented() && p2.is_implemented() {
+        top.bright_yellow().bold()
+    } else {
+        top.white()
+    };
+    let bottom = pad(format!("   {verified} {runtime}"));
+    let bottom = if verified.contains('✗') {
+        bottom.red()
+    } else {
+        bottom.normal()
+    };
+    [top, bottom]
+}
+
+/// Prints the year's 25 days as a grid, running each existing day to see which parts are done.
+pub fn print(year: &Year) {
+    let answers = vault::read_input(&format!("{}/answers.txt", year.year));
+    let known = parse_answers(&answers.unwrap_or_default());
+    println!("{}", format!("🎄Advent of Code {} 🎄", year.year).bold());
+    for row in 0..5 {
+        let cells = (1..=5)
+            .map(|column| {
+                let number = row * 5 + column;
+                cell(number, year.days.iter().find(|d| d.day == number), &known)
+            })
+            .collect::<Vec<_>>();
+        for line in 0..2 {
+            let line = cells.iter().map(|cell| cell[line].to_string());
+            println!("{}", line.collect::<String>().trim_end());
+        }
+        println!();
+    }
+    println!(
+        "{}",
+        "★ solved  ☆ pending  ✓ matches answers.txt  ✗ doesn't  yellow: disabled".dimmed()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let known = parse_answers("5 1 35\n5 2 46\n7 1 EZPCHZ\n\nnot an answer\n");
+        assert_eq!(known.len(), 3);
+        assert_eq!(known[&(5, 2)], Answer::Unsigned(46));
+        assert_eq!(known[&(7, 1)], Answer::from("EZPCHZ"));
+    }
+
+    #[test]
+    fn test_format_short() {
+        assert_eq!(format_short(Duration::from_nanos(324_498)), "324µs");
+        assert_eq!(format_short(Duration::from_micros(13_964)), "14.0ms");
+        assert_eq!(format_short(Duration::from_millis(2_546)), "2.55s");
+    }
+}
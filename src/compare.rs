@@ -0,0 +1,363 @@
+use crate::answer::Answer;
+use aoc_harness::Year;
+use colored::*;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+use std::{env, fs, io, process};
+
+/// Each revision is run this many times and the fastest time per phase is kept.
+const RUNS: usize = 5;
+
+#[derive(Debug, Default, PartialEq)]
+struct Benchmark {
+    parse: Option<Duration>,
+    parts: Vec<(Answer, Duration)>,
+}
+
+type Benchmarks = BTreeMap<usize, Benchmark>;
+
+/// A detached `git worktree` that is removed again when dropped.
+struct Worktree {
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn add(revision: &str, path: PathBuf) -> io::Result<Self> {
+        run(Command::new("git")
+            .args(["worktree", "add", "--detach", "--quiet"])
+            .arg(&path)
+            .arg(revision))?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let _ = Command::new("git")
+            .args(["worktree", "remove", "--force"])
+            .arg(&self.path)
+            .status();
+    }
+}
+
+fn run(command: &mut Command) -> io::Result<String> {
+    let output = command.stderr(Stdio::inherit()).output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!("{command:?} failed")));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses the `Debug` output of a `Duration`, such as `584.2µs` or `2.5463204s`.
+fn parse_duration(input: &str) -> Option<Duration> {
+    let split = input.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = input.split_at(split);
+    let value = value.parse::<f64>().ok()?;
+    let nanos = match unit {
+        "ns" => value,
+        "µs" => value * 1e3,
+        "ms" => value * 1e6,
+        "s" => value * 1e9,
+        _ => return None,
+    };
+    Some(Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Reads the day banners printed by a plain `aoc` run. Every revision prints these, so no
+/// cooperation is needed from the revisions being compared. A day's block ends at the blank line
+/// after it, or where the answers of a plugin start.
+fn parse_banners(output: &str) -> Benchmarks {
+    let mut benchmarks = Benchmarks::new();
+    let mut current = None;
+    for line in output.lines() {
+        if line.is_empty() || line.starts_with("Plugin ") {
+            current = None;
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("🎄Day ") {
+            let day = rest.split(' ').next().and_then(|d| d.parse::<usize>().ok());
+            current = day;
+            if let Some(day) = day {
+                benchmarks.insert(day, Benchmark::default());
+            }
+            continue;
+        }
+        let Some(benchmark) = current.and_then(|d| benchmarks.get_mut(&d)) else {
+            continue;
+        };
+        if let Some(rest) = line.strip_prefix("Parse : (") {
+            benchmark.parse = parse_duration(rest.trim_end_matches(')'));
+        } else if let Some(rest) = line.strip_prefix("Part ") {
+            let Some((_, rest)) = rest.split_once(": ") else {
+                continue;
+            };
+            if rest == "pending" {
+                benchmark
+                    .parts
+                    .push((Answer::Unimplemented, Duration::ZERO));
+                continue;
+            }
+            let Some((answer, duration)) = rest.rsplit_once(" (") else {
+                continue;
+            };
+            if let Some(duration) = parse_duration(duration.trim_end_matches(')')) {
+                let Ok(answer) = answer.parse();
+                benchmark.parts.push((answer, duration));
+            }
+        }
+    }
+    benchmarks
+}
+
+/// Keeps the fastest time per phase across several runs of the same revision.
+fn merge_fastest(best: &mut Benchmarks, run: Benchmarks) {
+    for (day, benchmark) in run {
+        let Some(existing) = best.get_mut(&day) else {
+            best.insert(day, benchmark);
+            continue;
+        };
+        existing.parse = match (existing.parse, benchmark.parse) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        for (existing, (_, duration)) in existing.parts.iter_mut().zip(benchmark.parts) {
+            existing.1 = existing.1.min(duration);
+        }
+    }
+}
+
+/// Builds `revision` in its own worktree and returns the path of a copy of the binary.
+fn build(revision: &str, scratch: &Path, index: usize) -> io::Result<PathBuf> {
+    let worktree = Worktree::add(revision, scratch.join(format!("worktree-{index}")))?;
+    println!("{} {revision}", "Building".bold().green());
+    let messages = run(Command::new("cargo")
+        .args(["build", "--release", "--offline", "--message-format=json"])
+        .arg("--target-dir")
+        .arg(scratch.join("target"))
+        .current_dir(&worktree.path))?;
+
+    // Revisions disagree on the binary name, so ask cargo where it put it. Both builds share a
+    // target directory to avoid compiling the dependencies twice, so copy it out of the way.
+    let executable = messages
+        .lines()
+        .filter_map(|line| line.split_once("\"executable\":\"")?.1.split_once('"'))
+        .map(|(path, _)| PathBuf::from(path))
+        .next_back()
+        .ok_or_else(|| io::Error::other(format!("No binary was built for {revision}")))?;
+    let copy = scratch.join(format!("aoc-{index}"));
+    fs::copy(executable, &copy)?;
+    Ok(copy)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &to.join(entry.file_name()))?;
+        } else {
+            fs::copy(entry.path(), to.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Sets up a directory to run the binaries in, with the inputs of the current checkout. Revisions
+/// from before the workspace split read `input/day_NN.txt`, so the plain inputs of `year` are
+/// also copied there.
+fn prepare_inputs(year: &Year, scratch: &Path) -> io::Result<PathBuf> {
+    let dir = scratch.join("run");
+    let year_dir = Path::new("input").join(year.year.to_string());
+    if !year_dir.is_dir() {
+        return Err(io::Error::other(format!(
+            "No inputs in {}",
+            year_dir.display()
+        )));
+    }
+    copy_dir(Path::new("input"), &dir.join("input"))?;
+    copy_dir(&year_dir, &dir.join("input"))?;
+    if Path::new("vault").is_dir() {
+        copy_dir(Path::new("vault"), &dir.join("vault"))?;
+    }
+    Ok(dir)
+}
+
+fn format_change(base: Duration, head: Duration) -> ColoredString {
+    if head.is_zero() || base.is_zero() {
+        return "-".normal();
+    }
+    let ratio = base.as_secs_f64() / head.as_secs_f64();
+    if ratio >= 1.0 {
+        format!("{ratio:.2}x faster").green()
+    } else {
+        format!("{:.2}x slower", 1.0 / ratio).red()
+    }
+}
+
+fn print_table(base_name: &str, head_name: &str, base: &Benchmarks, head: &Benchmarks) {
+    println!(
+        "\n{:<6} {:<7} {:>14} {:>14}   Change",
+        "Day", "Phase", base_name, head_name
+    );
+    let mut days = base.keys().chain(head.keys()).collect::<Vec<_>>();
+    days.sort();
+    days.dedup();
+
+    for day in days {
+        let (Some(b), Some(h)) = (base.get(day), head.get(day)) else {
+            let only = if base.contains_key(day) {
+                base_name
+            } else {
+                head_name
+            };
+            println!("{:<6} only in {only}", day);
+            continue;
+        };
+        let mut phases = vec![("parse", b.parse, h.parse)];
+        for (i, (b, h)) in b.parts.iter().zip(&h.parts).enumerate() {
+            let name = if i == 0 { "part 1" } else { "part 2" };
+            if b.0.is_implemented() && h.0.is_implemented() {
+                phases.push((name, Some(b.1), Some(h.1)));
+            }
+        }
+        let b_total = b.parse.unwrap_or_default() + b.parts.iter().map(|p| p.1).sum::<Duration>();
+        let h_total = h.parse.unwrap_or_default() + h.parts.iter().map(|p| p.1).sum::<Duration>();
+        phases.push(("total", Some(b_total), Some(h_total)));
+
+        for (name, b_time, h_time) in phases {
+            let (Some(b_time), Some(h_time)) = (b_time, h_time) else {
+                continue;
+            };
+            println!(
+                "{:<6} {:<7} {:>14} {:>14}   {}",
+                day,
+                name,
+                format!("{b_time:?}"),
+                format!("{h_time:?}"),
+                format_change(b_time, h_time)
+            );
+        }
+        let b_answers = b.parts.iter().map(|p| &p.0);
+        let h_answers = h.parts.iter().map(|p| &p.0);
+        for (part, (b_answer, h_answer)) in b_answers.zip(h_answers).enumerate() {
+            // Pending parts have nothing to verify.
+            if b_answer.is_implemented() && h_answer.is_implemented() && b_answer != h_answer {
+                let warning = format!(
+                    "⚠ Day {day} part {} answers differ: {b_answer} vs {h_answer}",
+                    part + 1
+                );
+                println!("{}", warning.bold().red());
+            }
+        }
+    }
+}
+
+/// Builds both revisions in temporary worktrees and benchmarks them against the inputs of the
+/// current checkout.
+pub fn compare(year: &Year, base: &str, head: &str) -> io::Result<()> {
+    let scratch = env::temp_dir().join(format!("aoc-compare-{}", process::id()));
+    fs::create_dir_all(&scratch)?;
+    let result = (|| {
+        let inputs = prepare_inputs(year, &scratch)?;
+        let binaries = [build(base, &scratch, 0)?, build(head, &scratch, 1)?];
+        let mut results = [Benchmarks::new(), Benchmarks::new()];
+        // Alternate between the binaries so that noise on the machine hits both equally.
+        for _ in 0..RUNS {
+            for (binary, result) in binaries.iter().zip(&mut results) {
+                let output = run(Command::new(binary)
+                    .env("NO_COLOR", "1")
+                    .current_dir(&inputs))?;
+                merge_fastest(result, parse_banners(&output));
+            }
+        }
+        print_table(base, head, &results[0], &results[1]);
+        Ok(())
+    })();
+    let _ = fs::remove_dir_all(&scratch);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("239ns"), Some(Duration::from_nanos(239)));
+        assert_eq!(
+            parse_duration("584.2µs"),
+            Some(Duration::from_nanos(584_200))
+        );
+        assert_eq!(
+            parse_duration("1.0597ms"),
+            Some(Duration::from_nanos(1_059_700))
+        );
+        assert_eq!(
+            parse_duration("2.5463204s"),
+            Some(Duration::from_nanos(2_546_320_400))
+        );
+        assert_eq!(parse_duration("12 parsecs"), None);
+    }
+
+    #[test]
+    fn test_parse_banners() {
+        let output = "🎄Day 1 (584.2µs) 🎄
+~~~~~~~~~~~~~~~~~~~~~
+Part 1: 55488 (278.1µs)
+Part 2: 55614 (306.1µs)
+
+🎄Day 2 (109.2µs) 🎄
+~~~~~~~~~~~~~~~~~~~~~
+Parse : (104.6µs)
+Part 1: 2685 (2.7µs)
+Part 2: 83707 (1.9µs)
+
+🎄Day 19 (147.9µs) 🎄
+~~~~~~~~~~~~~~~~~~~~~
+Parse : (130.3µs)
+Part 1: 19114 (17.6µs)
+Part 2: pending
+
+Final Runtime: 693.4µs
+";
+        let benchmarks = parse_banners(output);
+        assert_eq!(benchmarks.len(), 3);
+        assert_eq!(benchmarks[&1].parse, None);
+        assert_eq!(
+            benchmarks[&2],
+            Benchmark {
+                parse: Some(Duration::from_nanos(104_600)),
+                parts: vec![
+                    (Answer::Unsigned(2685), Duration::from_nanos(2_700)),
+                    (Answer::Unsigned(83707), Duration::from_nanos(1_900)),
+                ],
+            }
+        );
+        assert_eq!(benchmarks[&19].parts[1].0, Answer::Unimplemented);
+    }
+
+    #[test]
+    fn test_parse_banners_skips_plugins() {
+        let output = "🎄Day 6 (37.5µs) 🎄
+~~~~~~~~~~~~~~~~~~~~~
+Parse : (14.5µs)
+Part 1: 288 (5.5µs)
+Part 2: 71503 (17.5µs)
+Plugin `brute-force`
+Part 1: 288 (3.1µs)
+Part 2: 71503 (1.1795ms)
+
+Part 1: 1 (1s)
+";
+        let benchmarks = parse_banners(output);
+        assert_eq!(
+            benchmarks[&6].parts,
+            [
+                (Answer::Unsigned(288), Duration::from_nanos(5_500)),
+                (Answer::Unsigned(71503), Duration::from_nanos(17_500)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::answer::Answer;
+use crate::helpers::set_hash_seed;
+use crate::trace::Trace;
+use crate::{load_input, threads, Day};
+use colored::*;
+use rayon::ThreadPoolBuilder;
+
+/// Each combination of thread count and hash seed is run this many times.
+const RUNS: usize = 3;
+
+/// Hash seeds to try. Each one makes the maps and sets of the solvers iterate in another order.
+const HASH_SEEDS: [usize; 4] = [0, 1, 37, 0x9e37_79b9];
+
+fn answers(day: &Day, contents: &str, threads: usize) -> (Answer, Answer) {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("Failed to build thread pool.");
+    let (_, (p1, _), (p2, _)) = pool.install(|| (day.run)(contents, &Trace::default()));
+    (p1, p2)
+}
+
+/// Runs every day under different thread counts and hash seeds, and reports the days whose
+/// answers aren't always the same. Returns whether all of them were.
+pub fn check(days: &[Day]) -> bool {
+    let mut thread_counts = vec![1, 2, threads::available()];
+    thread_counts.sort_unstable();
+    thread_counts.dedup();
+
+    let mut deterministic = true;
+    for day in days.iter().filter(|d| d.enabled) {
+        let Ok(contents) = load_input(day, false) else {
+            println!("Day {:<3} {}", day.day, "no input".dimmed());
+            continue;
+        };
+        set_hash_seed(0);
+        let expected = answers(day, &contents, 1);
+        let mut differences = Vec::new();
+        for &threads in &thread_counts {
+            for seed in HASH_SEEDS {
+                set_hash_seed(seed);
+                for run in 1..=RUNS {
+                    let actual = answers(day, &contents, threads);
+                    if actual != expected {
+                        differences.push(format!(
+                            "{threads} threads, hash seed {seed:#x}, run {run}: {} / {}",
+                            actual.0, actual.1
+                        ));
+                    }
+                }
+            }
+        }
+        set_hash_seed(0);
+
+        let runs = thread_counts.len() * HASH_SEEDS.len() * RUNS;
+        if differences.is_empty() {
+            println!(
+                "Day {:<3} {} ({runs} runs)",
+                day.day,
+                "deterministic".green()
+            );
+        } else {
+            deterministic = false;
+            println!(
+                "Day {:<3} {} in {} of {runs} runs, expected {} / {}",
+                day.day,
+                "differs".bold().red(),
+                differences.len(),
+                expected.0,
+                expected.1
+            );
+            for difference in differences {
+                println!("  {difference}");
+            }
+        }
+    }
+    deterministic
+}
@@ -0,0 +1,145 @@
+use crate::Day;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The example and expected answer of one part, as given in the puzzle text.
+#[derive(Debug, PartialEq)]
+struct Example {
+    part: usize,
+    input: String,
+    answer: String,
+}
+
+/// Returns the text between each `open` and the following `close`.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+        found.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    found
+}
+
+/// Drops the tags the puzzle text uses for highlighting, and decodes the entities it escapes.
+fn to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Each part is an `<article>`. Its example is the first `<pre><code>` block, or the previous
+/// part's when it doesn't bring its own, and its answer is the last emphasized `<code>`.
+fn extract(html: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut input: Option<String> = None;
+    for (i, article) in between(html, "<article", "</article>").iter().enumerate() {
+        if let Some(block) = between(article, "<pre><code>", "</code></pre>").first() {
+            input = Some(to_text(block));
+        }
+        let answer = between(article, "<code><em>", "</em></code>").pop();
+        if let (Some(input), Some(answer)) = (&input, answer) {
+            examples.push(Example {
+                part: i + 1,
+                input: input.clone(),
+                answer: to_text(answer),
+            });
+        }
+    }
+    examples
+}
+
+/// Where the fixtures of a day live, next to the crate of its year.
+fn fixture_dir(day: &Day) -> PathBuf {
+    PathBuf::from(format!("years/{}/fixtures/day_{:0>2}", day.year, day.day))
+}
+
+/// Extracts the examples from a saved puzzle page and writes them as `part_N.txt` with the
+/// expected answer in `part_N_answer.txt`, which the year's tests then check.
+pub fn write_fixtures(day: &Day, page: &Path) -> io::Result<()> {
+    let examples = extract(&fs::read_to_string(page)?);
+    if examples.is_empty() {
+        return Err(io::Error::other(format!(
+            "No examples with answers found in {}",
+            page.display()
+        )));
+    }
+    let dir = fixture_dir(day);
+    fs::create_dir_all(&dir)?;
+    for example in examples {
+        let input = dir.join(format!("part_{}.txt", example.part));
+        fs::write(&input, &example.input)?;
+        fs::write(
+            dir.join(format!("part_{}_answer.txt", example.part)),
+            format!("{}\n", example.answer),
+        )?;
+        println!(
+            "Part {}: {} (expecting {})",
+            example.part,
+            input.display(),
+            example.answer
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 7: Camel Cards ---</h2><p>For example:</p>
+<pre><code>32T3K 765
+T55J5 684
+</code></pre>
+<p>The &lt;strongest&gt; hand is <code><em>T55J5</em></code>.</p>
+<p>So, the total winnings are <code><em>6440</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>250370104</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now the total is <code><em>5905</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        let input = String::from("32T3K 765\nT55J5 684\n");
+        assert_eq!(
+            extract(PAGE),
+            [
+                Example {
+                    part: 1,
+                    input: input.clone(),
+                    answer: String::from("6440"),
+                },
+                Example {
+                    part: 2,
+                    input,
+                    answer: String::from("5905"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_text() {
+        assert_eq!(to_text("<em>a</em> &lt;&amp;&gt;"), "a <&>");
+    }
+}
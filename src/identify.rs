@@ -0,0 +1,150 @@
+use crate::{Day, Year};
+use colored::*;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+/// Days accepting less of a file than this aren't worth listing.
+const MIN_CONFIDENCE: f64 = 0.5;
+
+/// How much of `input` a day's validator accepts, as the share of non-blank lines without
+/// violations. Blank lines separate sections in several days, so they say nothing on their own.
+/// Only a day whose validator finds nothing at all scores 1. Days without a validator can't tell.
+fn confidence(day: &Day, input: &str) -> Option<f64> {
+    let validate = day.validate?;
+    let input = day.prepare_input(input.to_string());
+    let violations = validate(&input);
+    if violations.is_empty() {
+        return Some(1.0);
+    }
+    let lines = input.lines().collect::<Vec<_>>();
+    let content = (1..=lines.len())
+        .filter(|&line| !lines[line - 1].is_empty())
+        .collect::<BTreeSet<_>>();
+    let bad_lines = violations
+        .iter()
+        .map(|violation| violation.line)
+        .filter(|line| content.contains(line))
+        .collect::<BTreeSet<_>>();
+    // Whatever was wrong, it wasn't a full match.
+    let good_lines = (content.len() - bad_lines.len()).min(content.len().saturating_sub(1));
+    Some(good_lines as f64 / content.len().max(1) as f64)
+}
+
+/// The days that accept enough of `input` to be likely, most confident first.
+fn rank<'a>(years: &'a [Year], input: &str) -> Vec<(&'a Day, f64)> {
+    let mut candidates = years
+        .iter()
+        .flat_map(|year| year.days)
+        .filter_map(|day| Some((day, confidence(day, input)?)))
+        .filter(|(_, confidence)| *confidence >= MIN_CONFIDENCE)
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+    candidates
+}
+
+/// Asks which of the days that accept the whole input to run. Declining, or a closed stdin, runs
+/// none.
+fn choose<'a>(best: &[&'a Day]) -> io::Result<Option<&'a Day>> {
+    let names = best
+        .iter()
+        .map(|day| day.day.to_string())
+        .collect::<Vec<_>>();
+    if best.len() == 1 {
+        print!("Run day {}? [Y/n] ", names[0]);
+    } else {
+        print!("Run which day? [{}/n] ", names.join("/"));
+    }
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    let answer = answer.trim();
+    Ok(match answer {
+        "" | "y" | "Y" if best.len() == 1 => Some(best[0]),
+        _ => best
+            .iter()
+            .zip(&names)
+            .find(|(_, name)| *name == answer)
+            .map(|(day, _)| *day),
+    })
+}
+
+/// Prints the days `path` could be the input of, and returns the one to run if the user picks
+/// one of the days that accept all of it. Only asks when run from a terminal, and never offers a
+/// partial match, since its solver may well panic on the rest.
+pub fn identify<'a>(years: &'a [Year], path: &Path) -> io::Result<Option<&'a Day>> {
+    let input = fs::read_to_string(path)?;
+    let candidates = rank(years, &input);
+    let Some(&(_, top)) = candidates.first() else {
+        println!("{} doesn't look like any day's input.", path.display());
+        return Ok(None);
+    };
+    println!("{} looks like:", path.display());
+    for (day, confidence) in candidates.iter().take(5) {
+        let line = format!(
+            "  {} day {:>2}  {:>3.0}%",
+            day.year,
+            day.day,
+            confidence * 100.0
+        );
+        if *confidence == top {
+            println!("{}", line.bold());
+        } else {
+            println!("{}", line.dimmed());
+        }
+    }
+    let best = candidates
+        .iter()
+        .filter(|(_, confidence)| *confidence == 1.0)
+        .map(|(day, _)| *day)
+        .collect::<Vec<_>>();
+    if best.is_empty() {
+        println!("No day accepts all of it, `aoc check-input` shows what doesn't fit.");
+        return Ok(None);
+    }
+    if !io::stdin().is_terminal() {
+        return Ok(None);
+    }
+    choose(&best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::YEARS;
+
+    fn best(input: &str) -> usize {
+        rank(&YEARS, input)[0].0.day
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(best("Game 1: 3 blue, 4 red; 1 red\nGame 2: 1 blue\n"), 2);
+        assert_eq!(
+            best("seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n"),
+            5
+        );
+        assert_eq!(best("32T3K 765\nT55J5 684\n"), 7);
+        assert_eq!(best("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n"), 8);
+        assert_eq!(best("0 3 6 9\n1 -3 6 10\n"), 9);
+        assert_eq!(best(".....\n.S-7.\n.|.|.\n.L-J.\n"), 10);
+        assert_eq!(best("rn=1,cm-,qp=3\n"), 15);
+        assert_eq!(
+            best("in{s<1351:px,R}\npx{A}\n\n{x=787,m=2655,a=1222,s=2876}\n"),
+            19
+        );
+        assert!(rank(&YEARS, "\u{1F384}\n").is_empty());
+    }
+
+    #[test]
+    fn test_rank_rejects_prose() {
+        let license = "MIT License\n\nCopyright (c) 2023\n\nPermission is hereby granted\n";
+        assert!(rank(&YEARS, license).is_empty());
+        // Mostly a day 5 almanac, but not quite.
+        let almanac = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n1 2\n";
+        let candidates = rank(&YEARS, almanac);
+        assert_eq!(candidates[0].0.day, 5);
+        assert!(candidates[0].1 < 1.0);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::answer::Answer;
+use crate::trace::Trace;
+use crate::{load_input, Day, DayResult};
+use std::env;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// The hidden command the runner starts its children with, as `aoc <year> run-isolated <day>`.
+pub const COMMAND: &str = "run-isolated";
+
+/// The high-water mark of this process's resident memory, in bytes.
+#[cfg(unix)]
+fn peak_rss() -> Option<u64> {
+    // SAFETY: `getrusage` only writes to the struct it is given, which is valid when zeroed.
+    let usage = unsafe {
+        let mut usage = std::mem::zeroed::<libc::rusage>();
+        if libc::getrusage(libc::RUSAGE_SELF, &mut usage) != 0 {
+            return None;
+        }
+        usage
+    };
+    let max_rss = usage.ru_maxrss as u64;
+    // Linux counts in kilobytes, macOS in bytes.
+    if cfg!(target_os = "macos") {
+        Some(max_rss)
+    } else {
+        Some(max_rss * 1024)
+    }
+}
+
+/// Only measured where `getrusage` is available.
+#[cfg(not(unix))]
+fn peak_rss() -> Option<u64> {
+    None
+}
+
+/// One `<key> <value>` line per field. Timings are in nanoseconds, and `-` marks a missing parse
+/// step, an unimplemented part or an unknown peak RSS.
+fn encode(result: &DayResult, peak_rss: Option<u64>) -> String {
+    let (parse, p1, p2) = result;
+    let mut out = match parse {
+        Some(parse) => format!("parse {}\n", parse.as_nanos()),
+        None => String::from("parse -\n"),
+    };
+    for (part, (answer, duration)) in [(1, p1), (2, p2)] {
+        if answer.is_implemented() {
+            out += &format!("part{part} {} {answer}\n", duration.as_nanos());
+        } else {
+            out += &format!("part{part} -\n");
+        }
+    }
+    match peak_rss {
+        Some(peak_rss) => out + &format!("rss {peak_rss}\n"),
+        None => out + "rss -\n",
+    }
+}
+
+fn decode(out: &str) -> Option<(DayResult, Option<u64>)> {
+    let mut lines = out
+        .lines()
+        .map(|line| line.split_once(' ').unwrap_or((line, "")));
+    let nanos = |value: &str| value.parse().ok().map(Duration::from_nanos);
+    let parse = match lines.next()? {
+        ("parse", "-") => None,
+        ("parse", value) => Some(nanos(value)?),
+        _ => return None,
+    };
+    let mut parts = Vec::new();
+    for key in ["part1", "part2"] {
+        parts.push(match lines.next()? {
+            (k, "-") if k == key => (Answer::Unimplemented, Duration::ZERO),
+            (k, value) if k == key => {
+                let (duration, answer) = value.split_once(' ')?;
+                let Ok(answer) = answer.parse();
+                (answer, nanos(duration)?)
+            }
+            _ => return None,
+        });
+    }
+    let rss = match lines.next()? {
+        ("rss", "-") => None,
+        ("rss", value) => Some(value.parse().ok()?),
+        _ => return None,
+    };
+    let p2 = parts.pop()?;
+    let p1 = parts.pop()?;
+    Some(((parse, p1, p2), rss))
+}
+
+/// Runs in the child: solves the day and reports to the parent over stdout.
+pub fn serve(day: &Day) {
+    let contents = load_input(day, false).expect("File not found.");
+    let result = (day.run)(&contents, &Trace::default());
+    let mut stdout = io::stdout().lock();
+    stdout
+        .write_all(encode(&result, peak_rss()).as_bytes())
+        .expect("Failed to report to the parent.");
+}
+
+/// Runs a day in a fresh process of this binary, so its peak memory isn't skewed by whatever ran
+/// before. Returns the result and the child's peak resident set size in bytes, if it's known.
+pub fn run(day: &Day, threads: Option<usize>) -> io::Result<(DayResult, Option<u64>)> {
+    let mut command = Command::new(env::current_exe()?);
+    command.args([
+        day.year.to_string(),
+        COMMAND.to_string(),
+        day.day.to_string(),
+    ]);
+    if let Some(threads) = threads {
+        command.args(["--threads".to_string(), threads.to_string()]);
+    }
+    let output = command.stderr(Stdio::inherit()).output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "Day {} exited with {}",
+            day.day, output.status
+        )));
+    }
+    decode(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| io::Error::other(format!("Day {} sent a garbled result", day.day)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let result = (
+            Some(Duration::from_nanos(99_786)),
+            (Answer::from("EZPCHZ"), Duration::from_nanos(466_031)),
+            (Answer::Unimplemented, Duration::from_nanos(12)),
+        );
+        let out = encode(&result, Some(3_145_728));
+        assert_eq!(
+            out,
+            "parse 99786\npart1 466031 EZPCHZ\npart2 -\nrss 3145728\n"
+        );
+        let (decoded, rss) = decode(&out).unwrap();
+        assert_eq!(decoded.0, result.0);
+        assert_eq!(decoded.1, result.1);
+        assert_eq!(decoded.2 .0, Answer::Unimplemented);
+        assert_eq!(rss, Some(3_145_728));
+        assert_eq!(decode("parse -\npart1 12\n"), None);
+        let (_, rss) = decode(&encode(&result, None)).unwrap();
+        assert_eq!(rss, None);
+    }
+}
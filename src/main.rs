@@ -1,39 +1,90 @@
-#![feature(extract_if)]
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day14;
-mod day15;
-mod day19;
-mod helpers;
+mod allocator;
+mod calendar;
+mod compare;
+mod determinism;
+mod examples;
+mod identify;
+mod isolate;
+mod report;
+mod scale;
+mod threads;
+mod tui;
 
+use aoc_harness::answer::Answer;
+use aoc_harness::plugins::Plugin;
+use aoc_harness::trace::Trace;
+use aoc_harness::vault::{self, Secret};
+use aoc_harness::{answer, generator, helpers, plugins, repl, trace, variants};
+use aoc_harness::{load_input, part_duration, total_duration, Day, DayResult, Year};
 use colored::Color::{Green, Red};
 use colored::*;
-use std::fs;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use std::io::{self, Write};
+use std::path::Path;
 use std::time::Duration;
+use std::{env, fs, process, slice};
 
-type DayResult = (Option<Duration>, (String, Duration), (String, Duration));
+#[global_allocator]
+static ALLOCATOR: allocator::CountingAllocator = allocator::CountingAllocator;
 
-fn run_day(day: usize, func: fn(&str) -> DayResult, color: Color) -> Duration {
+/// Every event with solutions, oldest first. Commands run against the last one unless the year is
+/// given first, e.g. `aoc 2023 5`.
+const YEARS: [Year; 1] = [aoc_2023::YEAR];
+
+fn print_part(part: usize, answer: &Answer, duration: Duration) {
+    print!("{}", format!("Part {part}: ").white());
+    if answer.is_implemented() {
+        print!("{}", answer.to_string().bold().white());
+        println!(" ({duration:?})");
+    } else {
+        println!("{}", answer.to_string().dimmed());
+    }
+}
+
+fn run_day(day: &Day, trace: &Trace, plugins: &[Plugin]) -> Duration {
     // Load the file before calling the function for accurate timing
-    let contents =
-        fs::read_to_string(format!("./input/day_{:0>2}.txt", day)).expect("File not found.");
+    let contents = load_input(day, false).expect("File not found.");
+    solve(day, &contents, trace, plugins)
+}
 
-    let (parse_duration, (p1, p1_duration), (p2, p2_duration)) = func(&contents);
-    let mut total_duration = p1_duration + p2_duration;
-    if let Some(p) = parse_duration {
-        total_duration += p;
+/// Runs the day and then any plugins for it, which don't count towards the runtime.
+fn solve(day: &Day, contents: &str, trace: &Trace, plugins: &[Plugin]) -> Duration {
+    if trace.enabled() {
+        println!("{}", format!("🔍Day {} explained 🔍", day.day).bold());
     }
+    let result = (day.run)(contents, trace);
+    print_day(day, &result);
+    for plugin in plugins
+        .iter()
+        .filter(|p| (p.year, p.day) == (day.year, day.day))
+    {
+        print_plugin(plugin, contents, &result);
+    }
+    println!();
+    total_duration(&result)
+}
 
+/// Shows a plugin's answers below the built-in ones, warning about any that differ.
+fn print_plugin(plugin: &Plugin, contents: &str, built_in: &DayResult) {
+    let (_, p1, p2) = plugin.run(contents);
+    println!("{}", format!("Plugin `{}`", plugin.name).white());
+    for (part, (answer, duration), (expected, _)) in [(1, &p1, &built_in.1), (2, &p2, &built_in.2)]
+    {
+        print_part(part, answer, *duration);
+        if answer.is_implemented() && expected.is_implemented() && answer != expected {
+            let warning = format!("⚠ The built-in part {part} answered {expected}");
+            println!("{}", warning.bold().red());
+        }
+    }
+}
+
+fn print_day(day: &Day, result: &DayResult) {
+    let total_duration = total_duration(result);
+    let (parse_duration, (p1, p1_duration), (p2, p2_duration)) = result;
+
+    let color = day.color;
+    let day = day.day;
     let title = match color {
         Red => format!("🎄Day {day} ({total_duration:?}) 🎄\n~~~~~~~~~~~~~~~~~~~~~").bright_red(),
         Green => {
@@ -45,31 +96,37 @@ fn run_day(day: usize, func: fn(&str) -> DayResult, color: Color) -> Duration {
     if let Some(p) = parse_duration {
         println!("Parse : ({p:?})");
     }
-    print!("{}", "Part 1: ".white());
-    print!("{}", p1.as_str().bold().white());
-    println!(" ({p1_duration:?})");
-    print!("{}", "Part 2: ".white());
-    print!("{}", p2.as_str().bold().white());
-    println!(" ({p2_duration:?})\n");
-    total_duration
+    print_part(1, p1, *p1_duration);
+    print_part(2, p2, *p2_duration);
 }
 
-fn main() {
+/// Like `run_day`, but in a child process, which also gives the day's own peak memory.
+fn run_isolated_day(day: &Day, threads: Option<usize>) -> Duration {
+    match isolate::run(day, threads) {
+        Ok((result, peak_rss)) => {
+            print_day(day, &result);
+            match peak_rss {
+                Some(peak_rss) => {
+                    let mib = peak_rss as f64 / (1024.0 * 1024.0);
+                    println!("{}", format!("Peak RSS: {mib:.1} MiB").white());
+                }
+                None => println!("{}", "Peak RSS: unknown on this platform".dimmed()),
+            }
+            println!();
+            total_duration(&result)
+        }
+        Err(e) => {
+            println!("{}\n", format!("⚠ {e}").bold().red());
+            Duration::ZERO
+        }
+    }
+}
+
+fn run_all(days: &[Day], run_day: impl Fn(&Day) -> Duration) {
     let mut final_runtime = Duration::new(0, 0);
-    final_runtime += run_day(1, day01::run, Red);
-    final_runtime += run_day(2, day02::run, Green);
-    final_runtime += run_day(3, day03::run, Red);
-    final_runtime += run_day(4, day04::run, Green);
-    final_runtime += run_day(5, day05::run, Red);
-    final_runtime += run_day(6, day06::run, Green);
-    final_runtime += run_day(7, day07::run, Red);
-    final_runtime += run_day(8, day08::run, Green);
-    final_runtime += run_day(9, day09::run, Red);
-    // final_runtime += run_day(10, day10::run, Green);
-    final_runtime += run_day(11, day11::run, Red);
-    final_runtime += run_day(14, day14::run, Green);
-    final_runtime += run_day(15, day15::run, Red);
-    final_runtime += run_day(19, day19::run, Red);
+    for day in days.iter().filter(|d| d.enabled) {
+        final_runtime += run_day(day);
+    }
 
     print!("{}", "Final Runtime: ".to_string().bold().white());
     if final_runtime < Duration::new(0, 800_000_000) {
@@ -80,3 +137,287 @@ fn main() {
         println!("{}", format!("{final_runtime:?}\n").bold().red());
     }
 }
+
+/// Reads a passphrase from the terminal without echoing it.
+fn read_passphrase(prompt: &str) -> io::Result<String> {
+    eprint!("{prompt}");
+    io::stderr().flush()?;
+    terminal::enable_raw_mode()?;
+    let mut passphrase = String::new();
+    let result = loop {
+        let key = match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => key,
+            Ok(_) => continue,
+            Err(e) => break Err(e),
+        };
+        match key.code {
+            KeyCode::Enter => break Ok(passphrase),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Err(io::Error::other("Cancelled."))
+            }
+            KeyCode::Char(c) => passphrase.push(c),
+            KeyCode::Backspace => {
+                passphrase.pop();
+            }
+            _ => {}
+        }
+    };
+    terminal::disable_raw_mode()?;
+    eprintln!();
+    result
+}
+
+/// Seals `input/` into the vault or opens it again, with the key from the environment or a
+/// passphrase typed in. A typed passphrase is asked twice when sealing.
+fn run_vault(seal: bool) -> io::Result<()> {
+    let secret = match Secret::from_env() {
+        Some(secret) => secret,
+        None => {
+            let passphrase = read_passphrase("Vault passphrase: ")?;
+            if seal && read_passphrase("Repeat it: ")? != passphrase {
+                return Err(io::Error::other("The passphrases don't match."));
+            }
+            Secret::Passphrase(passphrase)
+        }
+    };
+    let key = secret.key(seal)?;
+    if seal {
+        let sealed = vault::seal(&key)?;
+        println!("{sealed} files sealed into vault/, the rest were up to date.");
+    } else {
+        let opened = vault::open(&key)?;
+        println!("{opened} files opened into input/.");
+    }
+    Ok(())
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: aoc [<year>] [<day>] [--explain] [--threads <n>] [--variants] [--isolate]");
+    eprintln!("       aoc --compare-threads [--threads <n>]");
+    eprintln!("       aoc check-determinism");
+    eprintln!("       aoc generate <day> [--seed <n>] [--size <n>]");
+    eprintln!("       aoc scale <day>");
+    eprintln!("       aoc calendar");
+    eprintln!("       aoc examples <day> <page.html>");
+    eprintln!("       aoc check-input <day> [--example]");
+    eprintln!("       aoc identify <file>");
+    eprintln!("       aoc vault seal|open");
+    eprintln!("       aoc tui");
+    eprintln!("       aoc report --html <dir>");
+    eprintln!("       aoc compare <base> <head>");
+    eprintln!("       aoc repl <day> [--example]");
+    eprintln!("Commands take a year first to run another event's days.");
+    process::exit(1);
+}
+
+/// Removes `flag` from the arguments, returning whether it was given.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let found = args.iter().any(|a| a == flag);
+    args.retain(|a| a != flag);
+    found
+}
+
+/// Removes `option` and the value following it from the arguments.
+fn take_option(args: &mut Vec<String>, option: &str) -> Option<String> {
+    let index = args.iter().position(|a| a == option)?;
+    if index + 1 >= args.len() {
+        usage();
+    }
+    args.remove(index);
+    Some(args.remove(index))
+}
+
+/// Removes a leading year from the arguments, defaulting to the latest one.
+fn take_year(args: &mut Vec<String>) -> &'static Year {
+    let latest = YEARS.last().expect("No years are registered.");
+    let Some(year) = args.first().and_then(|a| a.parse::<u16>().ok()) else {
+        return latest;
+    };
+    // Anything up to 25 is a day.
+    if year <= 25 {
+        return latest;
+    }
+    args.remove(0);
+    match YEARS.iter().find(|y| y.year == year) {
+        Some(year) => year,
+        None => {
+            eprintln!("There are no solutions for {year}.");
+            process::exit(1);
+        }
+    }
+}
+
+fn find_day(days: &'static [Day], day: Option<&String>) -> &'static Day {
+    let day = day.and_then(|d| d.parse::<usize>().ok());
+    match days.iter().find(|d| Some(d.day) == day) {
+        Some(day) => day,
+        None => usage(),
+    }
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let trace = Trace::new(take_flag(&mut args, "--explain"));
+    let example = take_flag(&mut args, "--example");
+    let compare_threads = take_flag(&mut args, "--compare-threads");
+    let variants = take_flag(&mut args, "--variants");
+    let isolate = take_flag(&mut args, "--isolate");
+    let threads = take_option(&mut args, "--threads").map(|n| match n.parse::<usize>() {
+        Ok(n) if n > 0 => n,
+        _ => usage(),
+    });
+    if isolate && trace.enabled() {
+        eprintln!("--explain can't be combined with --isolate, the child reports over stdout.");
+        process::exit(1);
+    }
+    let year = take_year(&mut args);
+    let days = year.days;
+    if compare_threads {
+        threads::compare_threads(days, threads.unwrap_or_else(threads::available));
+        return;
+    }
+    if let Some(threads) = threads {
+        threads::configure(threads);
+    }
+
+    match args.first().map(String::as_str) {
+        None if variants => {
+            if !variants::check(days, &plugins::load_all(), &trace) {
+                process::exit(1);
+            }
+        }
+        None if isolate => run_all(days, |day| run_isolated_day(day, threads)),
+        None => {
+            let plugins = plugins::load_all();
+            run_all(days, |day| run_day(day, &trace, &plugins));
+        }
+        Some(day) if day.parse::<usize>().is_ok() && variants => {
+            let day = find_day(days, args.first());
+            let plugins = plugins::load_all();
+            if day.variants.is_empty()
+                && plugins
+                    .iter()
+                    .all(|p| (p.year, p.day) != (day.year, day.day))
+            {
+                eprintln!("Day {} has no variants or plugins.", day.day);
+                process::exit(1);
+            }
+            if !variants::check(slice::from_ref(day), &plugins, &trace) {
+                process::exit(1);
+            }
+        }
+        Some(day) if day.parse::<usize>().is_ok() && isolate => {
+            run_isolated_day(find_day(days, args.first()), threads);
+        }
+        Some(day) if day.parse::<usize>().is_ok() => {
+            run_day(find_day(days, args.first()), &trace, &plugins::load_all());
+        }
+        Some(isolate::COMMAND) => isolate::serve(find_day(days, args.get(1))),
+        Some("check-determinism") => {
+            if !determinism::check(days) {
+                process::exit(1);
+            }
+        }
+        Some("generate") => {
+            let day = find_day(days, args.get(1));
+            let mut number = |option| {
+                take_option(&mut args, option).map(|n| n.parse().unwrap_or_else(|_| usage()))
+            };
+            let seed = number("--seed").unwrap_or(0);
+            let size = number("--size").unwrap_or(10) as usize;
+            if size == 0 {
+                eprintln!("The size has to be at least 1.");
+                process::exit(1);
+            }
+            if let Err(e) = generator::print(day, seed, size) {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        Some("scale") => {
+            if let Err(e) = scale::scale(find_day(days, args.get(1))) {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        Some("calendar") => calendar::print(year),
+        Some("examples") => {
+            let (day, Some(page)) = (find_day(days, args.get(1)), args.get(2)) else {
+                usage();
+            };
+            if let Err(e) = examples::write_fixtures(day, Path::new(page)) {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        Some("check-input") => {
+            let day = find_day(days, args.get(1));
+            let Some(validate) = day.validate else {
+                eprintln!("Day {} has no input checks.", day.day);
+                process::exit(1);
+            };
+            let suffix = if example { "_example" } else { "" };
+            let path = format!("input/{}/day_{:0>2}{suffix}.txt", day.year, day.day);
+            let contents = load_input(day, example).expect("File not found.");
+            let violations = validate(&contents);
+            for violation in &violations {
+                println!("{path}:{violation}");
+            }
+            if !violations.is_empty() {
+                process::exit(1);
+            }
+            println!("{path} is well-formed.");
+        }
+        Some("identify") => {
+            let Some(path) = args.get(1) else {
+                usage();
+            };
+            match identify::identify(&YEARS, Path::new(path)) {
+                Ok(Some(day)) => {
+                    let contents = fs::read_to_string(path).expect("File not found.");
+                    println!();
+                    solve(day, &day.prepare_input(contents), &trace, &[]);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    eprintln!("{e}");
+                    process::exit(1);
+                }
+            }
+        }
+        Some("vault") => {
+            let seal = match args.get(1).map(String::as_str) {
+                Some("seal") => true,
+                Some("open") => false,
+                _ => usage(),
+            };
+            if let Err(e) = run_vault(seal) {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        Some("tui") => tui::run(days).expect("Terminal error."),
+        Some("report") => match (args.get(1).map(String::as_str), args.get(2)) {
+            (Some("--html"), Some(out)) => {
+                report::write_html(year, Path::new(out)).expect("Failed to write report.")
+            }
+            _ => usage(),
+        },
+        Some("compare") => match (args.get(1), args.get(2)) {
+            (Some(base), Some(head)) => {
+                compare::compare(year, base, head).expect("Comparison failed.")
+            }
+            _ => usage(),
+        },
+        Some("repl") => {
+            let day = find_day(days, args.get(1));
+            let Some(repl) = day.repl else {
+                eprintln!("Day {} has no REPL.", day.day);
+                process::exit(1);
+            };
+            let contents = load_input(day, example).expect("File not found.");
+            repl::run(day.day, repl(&contents).as_mut()).expect("Failed to read input.");
+        }
+        Some(_) => usage(),
+    }
+}
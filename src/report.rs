@@ -0,0 +1,338 @@
+use crate::allocator::allocations;
+use crate::trace::Trace;
+use crate::{load_input, part_duration, total_duration, Day, DayResult, Year};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The whole advent should finish within this.
+const GOAL: Duration = Duration::from_secs(1);
+
+const PARSE_COLOR: &str = "#95a5a6";
+const PART_1_COLOR: &str = "#c0392b";
+const PART_2_COLOR: &str = "#27ae60";
+
+struct Measurement {
+    day: usize,
+    result: DayResult,
+    allocations: usize,
+}
+
+fn measure(day: &Day) -> Option<Measurement> {
+    let contents = load_input(day, false).ok()?;
+    let before = allocations();
+    let result = (day.run)(&contents, &Trace::default());
+    Some(Measurement {
+        day: day.day,
+        result,
+        allocations: allocations() - before,
+    })
+}
+
+/// Runs every enabled day of `year` and writes `index.html` into `out`. Each report also appends
+/// its total runtime to `history-<year>.csv` next to it, which is charted once there is more than
+/// one run of that year.
+pub fn write_html(year: &Year, out: &Path) -> io::Result<()> {
+    fs::create_dir_all(out)?;
+
+    let mut missing = Vec::new();
+    let mut measurements = Vec::new();
+    for day in year.days.iter().filter(|d| d.enabled) {
+        match measure(day) {
+            Some(m) => measurements.push(m),
+            None => missing.push(day.day),
+        }
+    }
+    let total: Duration = measurements.iter().map(|m| total_duration(&m.result)).sum();
+
+    let history_path = out.join(format!("history-{}.csv", year.year));
+    let mut history = fs::read_to_string(&history_path).unwrap_or_default();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |t| t.as_secs());
+    writeln!(history, "{timestamp},{}", total.as_nanos()).unwrap();
+    fs::write(&history_path, &history)?;
+    let history = parse_history(&history);
+
+    let mut html = String::new();
+    writeln!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code {}</title>",
+        year.year
+    )
+    .unwrap();
+    html.push_str(
+        "<style>\n\
+         body { font-family: sans-serif; max-width: 860px; margin: 2em auto; color: #222; }\n\
+         table { border-collapse: collapse; width: 100%; }\n\
+         th, td { padding: 4px 8px; border-bottom: 1px solid #ddd; text-align: right; }\n\
+         th:first-child, td:first-child { text-align: left; }\n\
+         .good { color: #27ae60; } .bad { color: #c0392b; }\n\
+         </style>\n</head>\n<body>\n",
+    );
+    writeln!(html, "<h1>🎄 Advent of Code {} 🎄</h1>", year.year).unwrap();
+    let class = if total < GOAL { "good" } else { "bad" };
+    writeln!(
+        html,
+        "<p>Final runtime: <strong class=\"{class}\">{total:?}</strong> (goal {GOAL:?})</p>"
+    )
+    .unwrap();
+
+    html.push_str(&answer_table(&measurements));
+    if !missing.is_empty() {
+        let days = missing.iter().map(|d| d.to_string()).collect::<Vec<_>>();
+        writeln!(html, "<p>No input for day {}.</p>", days.join(", ")).unwrap();
+    }
+    html.push_str("<h2>Runtime per day</h2>\n");
+    html.push_str(&stacked_bars(&measurements));
+    html.push_str("<h2>Cumulative runtime</h2>\n");
+    html.push_str(&cumulative_curve(&measurements));
+    if history.len() > 1 {
+        html.push_str("<h2>History</h2>\n");
+        html.push_str(&history_curve(&history));
+    }
+    html.push_str("</body>\n</html>\n");
+
+    fs::write(out.join("index.html"), html)
+}
+
+fn parse_history(input: &str) -> Vec<(u64, Duration)> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let (timestamp, nanos) = line.split_once(',')?;
+            let nanos = nanos.parse::<u64>().ok()?;
+            Some((timestamp.parse().ok()?, Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+fn escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn answer_table(measurements: &[Measurement]) -> String {
+    let mut html = String::from(
+        "<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Parse</th>\
+         <th>Part 1 time</th><th>Part 2 time</th><th>Total</th><th>Allocations</th></tr>\n",
+    );
+    for m in measurements {
+        let (parse_duration, (p1, p1_duration), (p2, p2_duration)) = &m.result;
+        let [parse, p1_duration, p2_duration] = [
+            *parse_duration,
+            part_duration(p1, *p1_duration),
+            part_duration(p2, *p2_duration),
+        ]
+        .map(|d| d.map_or(String::from("-"), |d| format!("{d:?}")));
+        writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{parse}</td><td>{p1_duration}</td>\
+             <td>{p2_duration}</td><td>{:?}</td><td>{}</td></tr>",
+            m.day,
+            escape(&p1.to_string()),
+            escape(&p2.to_string()),
+            total_duration(&m.result),
+            m.allocations,
+        )
+        .unwrap();
+    }
+    html.push_str("</table>\n");
+    html
+}
+
+/// One horizontal bar per day, split into parse, part 1 and part 2 time. Bars share a linear
+/// scale so the slowest day fills the width.
+fn stacked_bars(measurements: &[Measurement]) -> String {
+    let (label_width, bar_width, row_height) = (70.0, 700.0, 22.0);
+    let slowest = measurements
+        .iter()
+        .map(|m| total_duration(&m.result).as_secs_f64())
+        .fold(0.0, f64::max);
+    let scale = if slowest > 0.0 {
+        bar_width / slowest
+    } else {
+        0.0
+    };
+    let height = row_height * (measurements.len() as f64 + 1.0);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{height}\" \
+         font-size=\"12\">\n",
+        label_width + bar_width
+    );
+    for (row, m) in measurements.iter().enumerate() {
+        let (parse_duration, (p1, p1_duration), (p2, p2_duration)) = &m.result;
+        let y = row as f64 * row_height;
+        writeln!(
+            svg,
+            "<text x=\"0\" y=\"{}\">Day {}</text>",
+            y + row_height * 0.7,
+            m.day
+        )
+        .unwrap();
+        let mut x = label_width;
+        let segments = [
+            (parse_duration.unwrap_or_default(), PARSE_COLOR, "Parse"),
+            (
+                part_duration(p1, *p1_duration).unwrap_or_default(),
+                PART_1_COLOR,
+                "Part 1",
+            ),
+            (
+                part_duration(p2, *p2_duration).unwrap_or_default(),
+                PART_2_COLOR,
+                "Part 2",
+            ),
+        ];
+        for (duration, color, name) in segments {
+            let width = duration.as_secs_f64() * scale;
+            writeln!(
+                svg,
+                "<rect x=\"{x:.1}\" y=\"{:.1}\" width=\"{width:.1}\" height=\"{:.1}\" \
+                 fill=\"{color}\"><title>{name}: {duration:?}</title></rect>",
+                y + 2.0,
+                row_height - 4.0
+            )
+            .unwrap();
+            x += width;
+        }
+    }
+    let legend_y = height - row_height * 0.3;
+    for (i, (color, name)) in [
+        (PARSE_COLOR, "Parse"),
+        (PART_1_COLOR, "Part 1"),
+        (PART_2_COLOR, "Part 2"),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let x = label_width + i as f64 * 90.0;
+        writeln!(
+            svg,
+            "<rect x=\"{x}\" y=\"{:.1}\" width=\"12\" height=\"12\" fill=\"{color}\"/>\
+             <text x=\"{}\" y=\"{legend_y:.1}\">{name}</text>",
+            legend_y - 10.0,
+            x + 16.0
+        )
+        .unwrap();
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Draws a polyline through `points` (x in 0..=1, y in seconds) with the goal as a dashed line.
+fn line_chart(points: &[(f64, f64)], x_labels: &[String]) -> String {
+    let (width, height, margin) = (800.0, 300.0, 40.0);
+    let highest = points
+        .iter()
+        .map(|p| p.1)
+        .fold(GOAL.as_secs_f64(), f64::max)
+        * 1.1;
+    let to_x = |x: f64| margin + x * (width - 2.0 * margin);
+    let to_y = |y: f64| height - margin - y / highest * (height - 2.0 * margin);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         font-size=\"12\">\n\
+         <line x1=\"{margin}\" y1=\"{0}\" x2=\"{1}\" y2=\"{0}\" stroke=\"#222\"/>\n\
+         <line x1=\"{margin}\" y1=\"{margin}\" x2=\"{margin}\" y2=\"{0}\" stroke=\"#222\"/>\n",
+        height - margin,
+        width - margin
+    );
+    let goal_y = to_y(GOAL.as_secs_f64());
+    writeln!(
+        svg,
+        "<line x1=\"{margin}\" y1=\"{goal_y:.1}\" x2=\"{}\" y2=\"{goal_y:.1}\" stroke=\"{}\" \
+         stroke-dasharray=\"6 4\"/><text x=\"{}\" y=\"{:.1}\" text-anchor=\"end\">goal \
+         {GOAL:?}</text>",
+        width - margin,
+        PART_1_COLOR,
+        width - margin,
+        goal_y - 4.0
+    )
+    .unwrap();
+
+    let polyline = points
+        .iter()
+        .map(|&(x, y)| format!("{:.1},{:.1}", to_x(x), to_y(y)))
+        .collect::<Vec<_>>();
+    writeln!(
+        svg,
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"/>",
+        polyline.join(" "),
+        PART_2_COLOR
+    )
+    .unwrap();
+    for (&(x, y), label) in points.iter().zip(x_labels) {
+        writeln!(
+            svg,
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"3\" fill=\"{}\">\
+             <title>{label}: {:?}</title></circle>\
+             <text x=\"{:.1}\" y=\"{}\" text-anchor=\"middle\">{label}</text>",
+            to_x(x),
+            to_y(y),
+            PART_2_COLOR,
+            Duration::from_secs_f64(y),
+            to_x(x),
+            height - margin + 16.0
+        )
+        .unwrap();
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn cumulative_curve(measurements: &[Measurement]) -> String {
+    let steps = measurements.len().max(1) as f64;
+    let mut cumulative = 0.0;
+    let mut points = vec![(0.0, 0.0)];
+    let mut labels = vec![String::new()];
+    for (i, m) in measurements.iter().enumerate() {
+        cumulative += total_duration(&m.result).as_secs_f64();
+        points.push(((i + 1) as f64 / steps, cumulative));
+        labels.push(m.day.to_string());
+    }
+    line_chart(&points, &labels)
+}
+
+fn history_curve(history: &[(u64, Duration)]) -> String {
+    let steps = (history.len() - 1).max(1) as f64;
+    let points = history
+        .iter()
+        .enumerate()
+        .map(|(i, (_, total))| (i as f64 / steps, total.as_secs_f64()))
+        .collect::<Vec<_>>();
+    let labels = (1..=history.len())
+        .map(|run| format!("#{run}"))
+        .collect::<Vec<_>>();
+    line_chart(&points, &labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_history() {
+        let history = parse_history("1701388800,2568201400\n\nbroken\n1701475200,584200\n");
+        assert_eq!(
+            history,
+            vec![
+                (1701388800, Duration::from_nanos(2568201400)),
+                (1701475200, Duration::from_nanos(584200)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+}
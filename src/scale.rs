@@ -0,0 +1,151 @@
+use crate::generator::Rng;
+use crate::trace::Trace;
+use crate::{part_duration, total_duration, Day};
+use colored::*;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+const FIRST_SIZE: usize = 8;
+const MAX_SIZE: usize = 1 << 14;
+
+/// Sizes keep doubling until a single run takes longer than this.
+const TIME_LIMIT: Duration = Duration::from_millis(500);
+
+/// Runs faster than this are repeated, keeping the fastest, to smooth out noise.
+const REPEAT_BELOW: Duration = Duration::from_millis(100);
+
+/// Fitting needs the largest input to be at least this many times longer than the smallest.
+const MIN_GROWTH: usize = 4;
+
+const PHASES: [&str; 4] = ["Parse", "Part 1", "Part 2", "Total"];
+
+/// Timings of one size, in the order of `PHASES`.
+type Timings = [Option<Duration>; 4];
+
+fn measure(day: &Day, input: &str) -> Timings {
+    let run = || {
+        let result = (day.run)(input, &Trace::default());
+        let (parse, (p1, p1_duration), (p2, p2_duration)) = &result;
+        [
+            *parse,
+            part_duration(p1, *p1_duration),
+            part_duration(p2, *p2_duration),
+            Some(total_duration(&result)),
+        ]
+    };
+    let mut best = run();
+    if best[3].unwrap_or_default() < REPEAT_BELOW {
+        for _ in 0..2 {
+            for (best, timing) in best.iter_mut().zip(run()) {
+                *best = (*best).min(timing);
+            }
+        }
+    }
+    best
+}
+
+/// Fits `time = c * n^k` through the points by least squares on their logarithms and returns
+/// `k`. Needs at least two points.
+fn fit_exponent(points: &[(usize, Duration)]) -> Option<f64> {
+    let points = points
+        .iter()
+        .filter(|(_, time)| !time.is_zero())
+        .map(|&(n, time)| ((n as f64).ln(), time.as_secs_f64().ln()))
+        .collect::<Vec<_>>();
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let covariance = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<f64>();
+    let variance = points
+        .iter()
+        .map(|(x, _)| (x - mean_x).powi(2))
+        .sum::<f64>();
+    Some(covariance / variance)
+}
+
+fn format_exponent(exponent: f64) -> ColoredString {
+    let text = format!("n^{exponent:.2}");
+    if exponent < 1.3 {
+        text.green()
+    } else if exponent < 2.3 {
+        text.yellow()
+    } else {
+        text.red()
+    }
+}
+
+/// Runs `day` on generated inputs of doubling size and estimates how each phase grows with the
+/// length of the input. Fitting against bytes rather than the generator's size keeps grids, whose
+/// size is their side, comparable with the line based days.
+pub fn scale(day: &Day) -> Result<(), String> {
+    let generate = day
+        .generate
+        .ok_or_else(|| format!("Day {} has no generator.", day.day))?;
+    println!(
+        "{:>8} {:>10} {:>14} {:>14} {:>14} {:>14}",
+        "Size", "Bytes", PHASES[0], PHASES[1], PHASES[2], PHASES[3]
+    );
+
+    let mut measurements: Vec<(usize, Timings)> = Vec::new();
+    let mut size = FIRST_SIZE;
+    while size <= MAX_SIZE {
+        let input = generate(&mut Rng::new(size as u64), size);
+        let Ok(timings) = panic::catch_unwind(AssertUnwindSafe(|| measure(day, &input))) else {
+            println!("{}", format!("The solver panicked at size {size}").red());
+            break;
+        };
+        let columns = timings.map(|t| t.map_or(String::from("-"), |t| format!("{t:?}")));
+        println!(
+            "{size:>8} {:>10} {:>14} {:>14} {:>14} {:>14}",
+            input.len(),
+            columns[0],
+            columns[1],
+            columns[2],
+            columns[3]
+        );
+        measurements.push((input.len(), timings));
+        if timings[3].unwrap_or_default() > TIME_LIMIT {
+            break;
+        }
+        size *= 2;
+    }
+
+    println!();
+    let bytes = measurements.iter().map(|(bytes, _)| *bytes);
+    if bytes.clone().max().unwrap_or_default() < MIN_GROWTH * bytes.min().unwrap_or_default() {
+        return Err(format!(
+            "Day {}'s input barely grows with its size.",
+            day.day
+        ));
+    }
+    for (phase, name) in PHASES.iter().enumerate() {
+        let points = measurements
+            .iter()
+            .filter_map(|(bytes, timings)| Some((*bytes, timings[phase]?)))
+            .collect::<Vec<_>>();
+        if let Some(exponent) = fit_exponent(&points) {
+            println!("{name:<7} grows with {}", format_exponent(exponent));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_exponent() {
+        let quadratic = [8, 16, 32, 64]
+            .map(|n: usize| (n, Duration::from_nanos(3 * n.pow(2) as u64)))
+            .to_vec();
+        assert!((fit_exponent(&quadratic).unwrap() - 2.0).abs() < 0.01);
+        assert_eq!(fit_exponent(&quadratic[..1]), None);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::trace::Trace;
+use crate::{load_input, total_duration, Day};
+use colored::*;
+use rayon::ThreadPoolBuilder;
+use std::time::Duration;
+
+/// Each thread count is run this many times and the fastest time is kept.
+const RUNS: usize = 5;
+
+/// Sets the size of rayon's global pool. With one thread the parallel iterators run the work in
+/// order on that thread, which keeps `--explain` output deterministic.
+pub fn configure(threads: usize) {
+    ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .expect("The thread pool was already initialized.");
+}
+
+/// The number of threads to scale up to when `--threads` isn't given.
+pub fn available() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+fn fastest_run(day: &Day, contents: &str, threads: usize) -> Duration {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("Failed to build thread pool.");
+    (0..RUNS)
+        .map(|_| total_duration(&pool.install(|| (day.run)(contents, &Trace::default()))))
+        .min()
+        .unwrap_or_default()
+}
+
+/// Runs every parallel day with 1 up to `max_threads` threads and prints the speedup over a
+/// single thread.
+pub fn compare_threads(days: &[Day], max_threads: usize) {
+    println!("{:<6} {:>7} {:>14}   Speedup", "Day", "Threads", "Runtime");
+    for day in days.iter().filter(|d| d.enabled && d.parallel) {
+        let Ok(contents) = load_input(day, false) else {
+            println!("{:<6} no input", day.day);
+            continue;
+        };
+        let single = fastest_run(day, &contents, 1);
+        for threads in 1..=max_threads {
+            let runtime = if threads == 1 {
+                single
+            } else {
+                fastest_run(day, &contents, threads)
+            };
+            let speedup = single.as_secs_f64() / runtime.as_secs_f64().max(f64::EPSILON);
+            let speedup = if speedup >= 1.0 {
+                format!("{speedup:.2}x").green()
+            } else {
+                format!("{speedup:.2}x").red()
+            };
+            println!(
+                "{:<6} {threads:>7} {:>14}   {speedup}",
+                day.day,
+                format!("{runtime:?}")
+            );
+        }
+    }
+}
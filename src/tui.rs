@@ -0,0 +1,279 @@
+use crate::trace::Trace;
+use crate::{load_input, part_duration, total_duration, Day, DayResult};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::ExecutableCommand;
+use ratatui::prelude::*;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Cell, Paragraph, Row, Table};
+use ratatui::widgets::{TableState, Wrap};
+use std::collections::VecDeque;
+use std::io::{self, stdout};
+use std::panic;
+use std::time::Duration;
+
+enum Status {
+    Pending,
+    Disabled,
+    MissingInput,
+    Panicked(String),
+    Done(DayResult),
+}
+
+struct Entry<'a> {
+    day: &'a Day,
+    example: bool,
+    status: Status,
+}
+
+impl Entry<'_> {
+    fn runtime(&self) -> Option<Duration> {
+        match &self.status {
+            Status::Done(result) => Some(total_duration(result)),
+            _ => None,
+        }
+    }
+
+    /// Runs the day against its selected input. Panics inside the solver are caught so that a bad
+    /// input doesn't tear down the whole dashboard.
+    fn rerun(&mut self) {
+        if !self.day.enabled {
+            self.status = Status::Disabled;
+            return;
+        }
+        let Ok(contents) = load_input(self.day, self.example) else {
+            self.status = Status::MissingInput;
+            return;
+        };
+        let run = self.day.run;
+        self.status = match panic::catch_unwind(|| run(&contents, &Trace::default())) {
+            Ok(result) => Status::Done(result),
+            Err(e) => {
+                let message = e
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| e.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Status::Panicked(message)
+            }
+        };
+    }
+}
+
+struct App<'a> {
+    entries: Vec<Entry<'a>>,
+    table: TableState,
+    /// Days waiting to be (re)run. One is run per tick so the chart fills in as results arrive.
+    queue: VecDeque<usize>,
+}
+
+impl<'a> App<'a> {
+    fn new(days: &'a [Day]) -> Self {
+        let entries = days
+            .iter()
+            .map(|day| Entry {
+                day,
+                example: false,
+                status: if day.enabled {
+                    Status::Pending
+                } else {
+                    Status::Disabled
+                },
+            })
+            .collect::<Vec<_>>();
+        let queue = (0..entries.len()).collect();
+        Self {
+            entries,
+            table: TableState::default().with_selected(Some(0)),
+            queue,
+        }
+    }
+
+    fn selected(&self) -> usize {
+        self.table.selected().unwrap_or(0)
+    }
+
+    fn select(&mut self, offset: isize) {
+        let last = self.entries.len() as isize - 1;
+        let index = (self.selected() as isize + offset).clamp(0, last);
+        self.table.select(Some(index as usize));
+    }
+
+    fn enqueue(&mut self, index: usize) {
+        if !self.queue.contains(&index) {
+            self.entries[index].status = Status::Pending;
+            self.queue.push_back(index);
+        }
+    }
+
+    fn tick(&mut self) {
+        if let Some(index) = self.queue.pop_front() {
+            self.entries[index].rerun();
+        }
+    }
+
+    fn total_runtime(&self) -> Duration {
+        self.entries.iter().filter_map(Entry::runtime).sum()
+    }
+}
+
+pub fn run(days: &[Day]) -> io::Result<()> {
+    enable_raw_mode()?;
+    stdout().execute(EnterAlternateScreen)?;
+    // Solver panics are reported in the table, the default hook would scribble over the screen.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+    let result = event_loop(&mut terminal, App::new(days));
+
+    panic::set_hook(hook);
+    disable_raw_mode()?;
+    stdout().execute(LeaveAlternateScreen)?;
+    result
+}
+
+fn event_loop(terminal: &mut Terminal<impl Backend>, mut app: App) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| draw(frame, &mut app))?;
+
+        // Don't block on input while there are still days left to run.
+        let timeout = if app.queue.is_empty() {
+            Duration::from_millis(250)
+        } else {
+            Duration::ZERO
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Up | KeyCode::Char('k') => app.select(-1),
+                    KeyCode::Down | KeyCode::Char('j') => app.select(1),
+                    KeyCode::Enter => app.enqueue(app.selected()),
+                    KeyCode::Char('e') => {
+                        let index = app.selected();
+                        app.entries[index].example = !app.entries[index].example;
+                        app.enqueue(index);
+                    }
+                    KeyCode::Char('a') => (0..app.entries.len()).for_each(|i| app.enqueue(i)),
+                    _ => (),
+                }
+            }
+        }
+        app.tick();
+    }
+}
+
+fn draw(frame: &mut Frame, app: &mut App) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),
+            Constraint::Length(app.entries.len() as u16 + 2),
+            Constraint::Length(1),
+        ])
+        .split(frame.size());
+
+    draw_table(frame, app, layout[0]);
+    draw_chart(frame, app, layout[1]);
+
+    let help =
+        Paragraph::new("↑/↓ select · enter rerun · e toggle example input · a rerun all · q quit")
+            .style(Style::default().fg(Color::DarkGray))
+            .wrap(Wrap { trim: true });
+    frame.render_widget(help, layout[2]);
+}
+
+fn draw_table(frame: &mut Frame, app: &mut App, area: Rect) {
+    let header = Row::new([
+        "Day", "Input", "Status", "Part 1", "Part 2", "Parse", "P1", "P2",
+    ])
+    .style(Style::default().bold());
+
+    let rows = app.entries.iter().map(|entry| {
+        let input = if entry.example { "example" } else { "real" };
+        let mut cells = vec![Cell::from(format!("{}", entry.day.day)), Cell::from(input)];
+        match &entry.status {
+            Status::Pending => cells.push(Cell::from("running…").yellow()),
+            Status::Disabled => cells.push(Cell::from("disabled").dark_gray()),
+            Status::MissingInput => cells.push(Cell::from("no input").red()),
+            Status::Panicked(message) => {
+                cells.push(Cell::from("panicked").red());
+                cells.push(Cell::from(message.clone()).red());
+            }
+            Status::Done((parse_duration, (p1, p1_duration), (p2, p2_duration))) => {
+                cells.push(Cell::from("ok").green());
+                cells.push(Cell::from(p1.to_string()).bold());
+                cells.push(Cell::from(p2.to_string()).bold());
+                cells.push(Cell::from(
+                    parse_duration.map_or(String::from("-"), |p| format!("{p:?}")),
+                ));
+                for (answer, duration) in [(p1, p1_duration), (p2, p2_duration)] {
+                    let duration = part_duration(answer, *duration);
+                    cells.push(Cell::from(
+                        duration.map_or(String::from("-"), |d| format!("{d:?}")),
+                    ));
+                }
+            }
+        }
+        Row::new(cells)
+    });
+
+    let widths = [
+        Constraint::Length(4),
+        Constraint::Length(8),
+        Constraint::Length(9),
+        Constraint::Min(16),
+        Constraint::Min(16),
+        Constraint::Length(11),
+        Constraint::Length(11),
+        Constraint::Length(11),
+    ];
+    let title = format!(" 🎄 Advent of Code 2023 ({:?}) 🎄 ", app.total_runtime());
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().reversed());
+    frame.render_stateful_widget(table, area, &mut app.table);
+}
+
+fn draw_chart(frame: &mut Frame, app: &App, area: Rect) {
+    let total = app.total_runtime().as_secs_f64();
+    let bars = app
+        .entries
+        .iter()
+        .map(|entry| {
+            let share = match entry.runtime() {
+                Some(runtime) if total > 0.0 => runtime.as_secs_f64() / total,
+                _ => 0.0,
+            };
+            let color = match entry.day.color {
+                colored::Color::Red => Color::LightRed,
+                colored::Color::Green => Color::LightGreen,
+                _ => Color::White,
+            };
+            Bar::default()
+                .label(Line::from(format!("Day {:>2}", entry.day.day)))
+                .value((share * 1000.0) as u64)
+                .text_value(format!("{:.1}%", share * 100.0))
+                .style(Style::default().fg(color))
+        })
+        .collect::<Vec<_>>();
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Share of total runtime "),
+        )
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(0)
+        .max(1000)
+        .data(BarGroup::default().bars(&bars));
+    frame.render_widget(chart, area);
+}
@@ -0,0 +1,45 @@
+/* Generated from src/ffi.rs by `AOC_UPDATE_HEADER=1 cargo test`, don't edit. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The answer was written to `out`. */
+#define AOC_OK 0
+/* There is no solver for the day. */
+#define AOC_UNKNOWN_DAY 1
+/* The part isn't 1 or 2. */
+#define AOC_UNKNOWN_PART 2
+/* A pointer is null or the input isn't UTF-8. */
+#define AOC_INVALID_INPUT 3
+/* The part isn't solved yet. */
+#define AOC_UNIMPLEMENTED 4
+/* The solver failed on the input. */
+#define AOC_PANICKED 5
+
+struct AocAnswer {
+    /* Owned by the library until it's handed to aoc_free. */
+    char *answer;
+    /* Zero for days that don't parse separately. */
+    uint64_t parse_nanos;
+    uint64_t part_nanos;
+};
+
+/* Solves one part of a day for `len` bytes of input, writing the answer to `out` and returning
+ * AOC_OK, or returning an error code and leaving `out` alone. */
+int32_t aoc_solve(uint32_t day, uint32_t part, const uint8_t *input, size_t len,
+                  struct AocAnswer *out);
+
+/* Frees an answer returned by aoc_solve. Null is ignored. */
+void aoc_free(char *answer);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,167 @@
+use crate::generator::Rng;
+use crate::trace::{explain, Trace};
+use crate::validate::Violation;
+use crate::{DayResult, PartResult};
+use rayon::prelude::*;
+use std::time::Instant;
+
+pub fn run(input: &str, trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let p1 = part_1(input, trace).into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(input, trace).into();
+    let p2_duration = start.elapsed();
+    (None, (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(input, trace).into(),
+        _ => part_2(input, trace).into(),
+    };
+    (None, (answer, start.elapsed()))
+}
+
+#[inline(always)]
+fn part_1(input: &str, trace: &Trace) -> usize {
+    input
+        .par_lines()
+        .map(|l| {
+            let value = get_line_value(l);
+            explain!(trace, "{l} → {value}");
+            value
+        })
+        .sum()
+}
+
+#[inline(always)]
+fn part_2(input: &str, trace: &Trace) -> usize {
+    input
+        .par_lines()
+        .map(|l| {
+            let value = get_line_value(replace_words(l).as_str());
+            explain!(trace, "{l} → {value}");
+            value
+        })
+        .sum()
+}
+
+/// Finds the first and last digits within a line and creates a two-digit number from them.
+#[inline(always)]
+fn get_line_value(input: &str) -> usize {
+    let mut first = 0;
+    let mut last = 0;
+    for mut c in input.bytes() {
+        c -= 48;
+        if c <= 9 {
+            last = c;
+            if first == 0 {
+                first = c;
+            }
+        }
+    }
+    first as usize * 10 + last as usize
+}
+
+/// We replace words with a number, but we MUST keep the first and last letters in case there are
+/// words like `oneeight` or `eightwo`. Sneaky for day 1!
+#[inline(always)]
+fn replace_words(input: &str) -> String {
+    input
+        .replace("one", "o1e")
+        .replace("two", "t2o")
+        .replace("three", "t3e")
+        .replace("four", "f4r")
+        .replace("five", "f5e")
+        .replace("six", "s6x")
+        .replace("seven", "s7n")
+        .replace("eight", "e8t")
+        .replace("nine", "n9e")
+}
+
+/// Every line is lowercase letters and digits.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        let unexpected = line
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_lowercase() && !c.is_ascii_digit());
+        if line.is_empty() {
+            violations.push(Violation::new(y + 1, 1, "empty line"));
+        } else if let Some((x, c)) = unexpected {
+            violations.push(Violation::new(y + 1, x + 1, format!("unexpected `{c}`")));
+        }
+    }
+    violations
+}
+
+/// Lines of letters with at least one digit, and spelled out digits mixed in.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let lines = (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            let digit_at = rng.range(0, 5);
+            for i in 0..=5 {
+                match rng.range(0, 3) {
+                    0 | 1 => line.push(rng.pick(b"abcdefghijklmnopqrstuvwxyz") as char),
+                    2 => line.push_str(rng.pick(&WORDS)),
+                    _ => line.push(rng.pick(b"123456789") as char),
+                }
+                if i == digit_at {
+                    line.push(rng.pick(b"123456789") as char);
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let result = part_1(
+            "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+",
+            &Trace::default(),
+        );
+        assert_eq!(result, 142);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = part_2(
+            "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+",
+            &Trace::default(),
+        );
+        assert_eq!(result, 281);
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        assert_eq!(part_2("oneight", &Trace::default()), 18);
+        assert_eq!(part_2("nineight", &Trace::default()), 98);
+        assert_eq!(part_2("eightwo", &Trace::default()), 82);
+    }
+}
@@ -1,21 +1,38 @@
-use crate::DayResult;
+use crate::generator::Rng;
+use crate::trace::Trace;
+use crate::validate::Violation;
+use crate::{DayResult, PartResult};
 use std::time::Instant;
 
-pub fn run(input: &str) -> DayResult {
+pub fn run(input: &str, trace: &Trace) -> DayResult {
     let start = Instant::now();
     let games: Vec<GameResults> = parse_games(input);
     let parse_duration = start.elapsed();
 
     let start = Instant::now();
-    let p1 = part_1(&games).to_string();
+    let p1 = part_1(&games, trace).into();
     let p1_duration = start.elapsed();
 
     let start = Instant::now();
-    let p2 = part_2(&games).to_string();
+    let p2 = part_2(&games).into();
     let p2_duration = start.elapsed();
     (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
 }
 
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let games: Vec<GameResults> = parse_games(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&games, trace).into(),
+        _ => part_2(&games).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
 #[derive(Default, Copy, Clone, Debug)]
 struct CubeCounts {
     red: usize,
@@ -45,7 +62,7 @@ impl GameResults {
             .next()
             .unwrap()
             .split(' ')
-            .last()
+            .next_back()
             .unwrap()
             .parse::<usize>()
             .unwrap();
@@ -80,7 +97,7 @@ fn parse_games(input: &str) -> Vec<GameResults> {
 }
 
 #[inline(always)]
-fn part_1(games: &Vec<GameResults>) -> usize {
+fn part_1(games: &Vec<GameResults>, trace: &Trace) -> usize {
     // Rules given by the prompt
     let max_red = 12;
     let max_green = 13;
@@ -92,6 +109,18 @@ fn part_1(games: &Vec<GameResults>) -> usize {
         let counts = g.get_max_counts();
         if counts.red <= max_red && counts.blue <= max_blue && counts.green <= max_green {
             sum_of_valid_game_ids += g.id;
+        } else if trace.enabled() {
+            let limits = [
+                ("red", counts.red, max_red),
+                ("green", counts.green, max_green),
+                ("blue", counts.blue, max_blue),
+            ];
+            let failed = limits
+                .iter()
+                .filter(|(_, count, max)| count > max)
+                .map(|(color, count, max)| format!("{count} {color} > {max}"))
+                .collect::<Vec<_>>();
+            trace.log(format_args!("Game {} failed: {}", g.id, failed.join(", ")));
         }
     }
     sum_of_valid_game_ids
@@ -107,6 +136,59 @@ fn part_2(games: &Vec<GameResults>) -> usize {
     power_sum
 }
 
+/// Every line is `Game <id>: ` followed by draws like `3 blue, 4 red` separated by `; `.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        let Some((game, draws)) = line.split_once(": ") else {
+            violations.push(Violation::new(y + 1, 1, "expected `Game <id>: `"));
+            continue;
+        };
+        if game
+            .strip_prefix("Game ")
+            .is_none_or(|id| id.parse::<usize>().is_err())
+        {
+            violations.push(Violation::new(y + 1, 1, "expected `Game <id>`"));
+        }
+        // Both separators are two characters long.
+        let mut column = game.len() + 3;
+        for entry in draws.split("; ").flat_map(|draw| draw.split(", ")) {
+            match entry.split_once(' ') {
+                Some((count, "red" | "green" | "blue")) if count.parse::<usize>().is_ok() => {}
+                _ => {
+                    let message = format!("expected `<count> <color>`, found `{entry}`");
+                    violations.push(Violation::new(y + 1, column, message));
+                }
+            }
+            column += entry.len() + 2;
+        }
+    }
+    violations
+}
+
+/// `size` games of up to six draws, each showing at least one colour.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let games = (1..=size)
+        .map(|id| {
+            let draws = (0..rng.range(1, 6))
+                .map(|_| {
+                    let mut colors = vec!["red", "green", "blue"];
+                    for _ in 0..rng.range(0, 2) {
+                        colors.remove(rng.range(0, colors.len() - 1));
+                    }
+                    let cubes = colors
+                        .into_iter()
+                        .map(|color| format!("{} {color}", rng.range(1, 20)))
+                        .collect::<Vec<_>>();
+                    cubes.join(", ")
+                })
+                .collect::<Vec<_>>();
+            format!("Game {id}: {}", draws.join("; "))
+        })
+        .collect::<Vec<_>>();
+    games.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -114,13 +196,16 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let result = part_1(&parse_games(
-            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        let result = part_1(
+            &parse_games(
+                "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
-        ));
+            ),
+            &Trace::default(),
+        );
         assert_eq!(result, 8);
     }
 
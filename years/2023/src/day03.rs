@@ -1,22 +1,39 @@
-use crate::DayResult;
-use fxhash::{FxBuildHasher, FxHashMap, FxHashSet};
+use crate::generator::Rng;
+use crate::helpers::{map_with_capacity, FxHashMap, FxHashSet};
+use crate::trace::Trace;
+use crate::validate::{check_grid, Violation};
+use crate::{DayResult, PartResult};
 use std::time::Instant;
 
-pub fn run(input: &str) -> DayResult {
+pub fn run(input: &str, _trace: &Trace) -> DayResult {
     let start = Instant::now();
     let (schematic, parts) = parse_schematic(input);
     let parse_duration = start.elapsed();
 
     let start = Instant::now();
-    let p1 = part_1(&schematic, &parts).to_string();
+    let p1 = part_1(&schematic, &parts).into();
     let p1_duration = start.elapsed();
 
     let start = Instant::now();
-    let p2 = part_2(&schematic, &parts).to_string();
+    let p2 = part_2(&schematic, &parts).into();
     let p2_duration = start.elapsed();
     (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
 }
 
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, _trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let (schematic, parts) = parse_schematic(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&schematic, &parts).into(),
+        _ => part_2(&schematic, &parts).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
 /// Part numbers may span across multiple grid entries.
 type Schematic = FxHashMap<(isize, isize), Cell>;
 type Parts = FxHashMap<usize, Part>;
@@ -31,7 +48,7 @@ struct Part {
 #[derive(Debug, Clone)]
 enum Cell {
     Blank,
-    Symbol { value: String },
+    Symbol { value: char },
     Part { part_id: usize },
 }
 
@@ -46,9 +63,22 @@ fn get_part_value(decoder: &[usize]) -> usize {
     value
 }
 
+/// Stores the number in `decoder`, if there is one, as the part with `part_id`.
+fn flush_decoder(parts: &mut Parts, part_id: usize, decoder: &mut Vec<usize>) {
+    if !decoder.is_empty() {
+        parts.insert(
+            part_id,
+            Part {
+                value: get_part_value(decoder),
+            },
+        );
+        decoder.clear();
+    }
+}
+
 fn parse_schematic(input: &str) -> (Schematic, Parts) {
-    let mut schematic = Schematic::with_capacity_and_hasher(5000, FxBuildHasher::default());
-    let mut parts = Parts::with_capacity_and_hasher(500, FxBuildHasher::default());
+    let mut schematic: Schematic = map_with_capacity(5000);
+    let mut parts: Parts = map_with_capacity(500);
 
     let mut part_id: usize = 0;
     let mut part_decoder: Vec<usize> = Vec::with_capacity(100);
@@ -57,40 +87,27 @@ fn parse_schematic(input: &str) -> (Schematic, Parts) {
         for (x, cell) in line.chars().enumerate() {
             let x = x as isize;
             let y = y as isize;
-            let mut flush_decoder = false;
 
             match cell {
                 '0'..='9' => {
                     if part_decoder.is_empty() {
                         part_id += 1;
                     }
-                    part_decoder.push(cell.to_string().parse::<usize>().unwrap());
+                    part_decoder.push(cell.to_digit(10).unwrap() as usize);
                     schematic.insert((x, y), Cell::Part { part_id });
                 }
                 '.' => {
                     schematic.insert((x, y), Cell::Blank);
-                    flush_decoder = true;
+                    flush_decoder(&mut parts, part_id, &mut part_decoder);
                 }
                 _ => {
-                    schematic.insert(
-                        (x, y),
-                        Cell::Symbol {
-                            value: cell.to_string(),
-                        },
-                    );
-                    flush_decoder = true;
+                    schematic.insert((x, y), Cell::Symbol { value: cell });
+                    flush_decoder(&mut parts, part_id, &mut part_decoder);
                 }
             }
-            if flush_decoder && !part_decoder.is_empty() {
-                parts.insert(
-                    part_id,
-                    Part {
-                        value: get_part_value(&part_decoder),
-                    },
-                );
-                part_decoder.clear();
-            }
         }
+        // A number at the end of a line ends there, it doesn't run on into the next line.
+        flush_decoder(&mut parts, part_id, &mut part_decoder);
     }
     (schematic, parts)
 }
@@ -172,7 +189,7 @@ fn part_2(schematic: &Schematic, parts: &Parts) -> usize {
         .iter()
         .filter(|&((_x, _y), cell)| {
             if let Cell::Symbol { value } = cell {
-                value == "*"
+                *value == '*'
             } else {
                 false
             }
@@ -185,6 +202,43 @@ fn part_2(schematic: &Schematic, parts: &Parts) -> usize {
     ratio_total
 }
 
+/// A grid of digits, `.` and symbols.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let allowed = (b'!'..=b'~')
+        .map(char::from)
+        .filter(|c| !c.is_ascii_alphabetic())
+        .collect::<String>();
+    check_grid(input, &allowed)
+}
+
+/// A square schematic of numbers and symbols. Numbers may end at the end of a line.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size + 4;
+    let lines = (0..width)
+        .map(|_| {
+            let mut line = String::with_capacity(width);
+            while line.len() < width {
+                let digits = rng.range(1, 3);
+                match rng.range(0, 9) {
+                    0 => line.push(rng.pick(b"*#+$/@=%&-") as char),
+                    1..=3 if line.len() + digits <= width => {
+                        line.push(rng.pick(b"123456789") as char);
+                        for _ in 1..digits {
+                            line.push(rng.pick(b"0123456789") as char);
+                        }
+                        if line.len() < width {
+                            line.push('.');
+                        }
+                    }
+                    _ => line.push('.'),
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+    lines.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -224,9 +278,25 @@ mod tests {
         assert_eq!(part_2(&schematic, &parts), 467835);
     }
 
+    #[test]
+    fn test_numbers_at_line_end() {
+        let (schematic, parts) = parse_schematic("..12\n34*.\n.*56");
+        assert_eq!(part_1(&schematic, &parts), 12 + 34 + 56);
+        assert_eq!(part_2(&schematic, &parts), 34 * 56);
+    }
+
+    #[test]
+    fn test_generate_ends_lines_in_numbers() {
+        let input = generate(&mut Rng::new(1), 20);
+        assert!(input.lines().all(|line| line.len() == 24));
+        assert!(input
+            .lines()
+            .any(|line| line.ends_with(|c: char| c.is_ascii_digit())));
+    }
+
     #[test]
     fn test_get_part_value() {
-        assert_eq!(get_part_value(&vec![1, 2, 3]), 123);
-        assert_eq!(get_part_value(&vec![4, 5, 6, 7, 8]), 45678);
+        assert_eq!(get_part_value(&[1, 2, 3]), 123);
+        assert_eq!(get_part_value(&[4, 5, 6, 7, 8]), 45678);
     }
 }
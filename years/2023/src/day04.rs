@@ -1,8 +1,11 @@
-use crate::DayResult;
-use fxhash::{FxHashMap, FxHashSet};
+use crate::generator::Rng;
+use crate::helpers::{FxHashMap, FxHashSet};
+use crate::trace::{explain, Trace};
+use crate::validate::{check_numbers, Violation};
+use crate::{DayResult, PartResult};
 use std::time::Instant;
 
-pub fn run(input: &str) -> DayResult {
+pub fn run(input: &str, trace: &Trace) -> DayResult {
     let start = Instant::now();
     let mut cards = Vec::with_capacity(250);
     input
@@ -12,15 +15,33 @@ pub fn run(input: &str) -> DayResult {
     let parse_duration = start.elapsed();
 
     let start = Instant::now();
-    let p1 = part_1(&cards).to_string();
+    let p1 = part_1(&cards).into();
     let p1_duration = start.elapsed();
 
     let start = Instant::now();
-    let p2 = part_2(&cards).to_string();
+    let p2 = part_2(&cards, trace).into();
     let p2_duration = start.elapsed();
     (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
 }
 
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let mut cards = Vec::with_capacity(250);
+    input
+        .lines()
+        .map(Scratchcard::new)
+        .for_each(|c| cards.push(c));
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&cards).into(),
+        _ => part_2(&cards, trace).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
 #[derive(Debug, Clone)]
 struct Scratchcard {
     id: usize,
@@ -35,7 +56,7 @@ impl Scratchcard {
             .next()
             .unwrap()
             .split(' ')
-            .last()
+            .next_back()
             .unwrap()
             .parse::<usize>()
             .unwrap();
@@ -90,7 +111,7 @@ fn part_1(cards: &[Scratchcard]) -> usize {
     cards.iter().map(|c| c.get_worth()).sum()
 }
 
-fn part_2(cards: &[Scratchcard]) -> usize {
+fn part_2(cards: &[Scratchcard], trace: &Trace) -> usize {
     let mut cards_to_run: FxHashMap<usize, usize> = FxHashMap::default();
     for card in cards {
         let mut multiplier = 1;
@@ -101,7 +122,13 @@ fn part_2(cards: &[Scratchcard]) -> usize {
         }
 
         // Add additional card iterations
-        for n in card.id + 1..=card.id + card.num_matches() {
+        let matches = card.num_matches();
+        explain!(
+            trace,
+            "Card {}: {multiplier} copies, {matches} matches",
+            card.id
+        );
+        for n in card.id + 1..=card.id + matches {
             if let Some(v) = cards_to_run.get_mut(&n) {
                 *v += multiplier;
             } else {
@@ -112,6 +139,67 @@ fn part_2(cards: &[Scratchcard]) -> usize {
     cards_to_run.values().sum::<usize>()
 }
 
+/// Every line is `Card <id>:` followed by the winning and revealed numbers separated by `|`.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        let Some((card, numbers)) = line.split_once(':') else {
+            violations.push(Violation::new(y + 1, 1, "expected `Card <id>:`"));
+            continue;
+        };
+        if card
+            .strip_prefix("Card")
+            .is_none_or(|id| id.trim().parse::<usize>().is_err())
+        {
+            violations.push(Violation::new(y + 1, 1, "expected `Card <id>`"));
+        }
+        let column = card.len() + 2;
+        let Some((winning, revealed)) = numbers.split_once('|') else {
+            let message = "expected `|` between the numbers";
+            violations.push(Violation::new(y + 1, column, message));
+            continue;
+        };
+        violations.extend(check_numbers(y + 1, column, winning, false));
+        violations.extend(check_numbers(
+            y + 1,
+            column + winning.len() + 1,
+            revealed,
+            false,
+        ));
+    }
+    violations
+}
+
+/// `size` scratchcards with five winning numbers and eight numbers you have. Cards never win
+/// copies of cards past the end of the table.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let cards = (1..=size)
+        .map(|id| {
+            let mut numbers = Vec::with_capacity(13);
+            while numbers.len() < 13 {
+                let number = rng.range(1, 99);
+                if !numbers.contains(&number) {
+                    numbers.push(number);
+                }
+            }
+            let (winning, others) = numbers.split_at(5);
+            let matches = rng.range(0, 5.min(size - id));
+            let mut have = winning[..matches].to_vec();
+            have.extend_from_slice(&others[..8 - matches]);
+            rng.shuffle(&mut have);
+            let column = |numbers: &[usize]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            format!("Card {id:>3}: {} | {}", column(winning), column(&have))
+        })
+        .collect::<Vec<_>>();
+    cards.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -140,6 +228,6 @@ Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
 ";
         let cards: Vec<Scratchcard> = input.lines().map(Scratchcard::new).collect();
-        assert_eq!(part_2(&cards), 30);
+        assert_eq!(part_2(&cards, &Trace::default()), 30);
     }
 }
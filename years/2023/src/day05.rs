@@ -0,0 +1,588 @@
+use crate::generator::Rng;
+use crate::helpers::{new_map, FxHashMap};
+use crate::repl::{parse_arg, Command, Session};
+use crate::trace::{explain, Trace};
+use crate::validate::{check_numbers, fields, Violation};
+use crate::variants::Variant;
+use crate::{DayResult, PartResult};
+use rayon::prelude::*;
+use std::ops::Range;
+use std::time::Instant;
+
+pub fn run(input: &str, trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let (seeds, mappers) = parse_input(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p1 = part_1(&seeds, &mappers, trace).into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(&seeds, &mappers, trace).into();
+    let p2_duration = start.elapsed();
+    (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let (seeds, mappers) = parse_input(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&seeds, &mappers, trace).into(),
+        _ => part_2(&seeds, &mappers, trace).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
+pub const VARIANTS: &[Variant] = &[
+    Variant::new(1, "ranges", |input, trace| {
+        let (seeds, mappers) = parse_input(input);
+        part_1_ranges(&seeds, &mappers, trace).into()
+    }),
+    Variant::new(2, "breakpoints", |input, trace| {
+        let (seeds, mappers) = parse_input(input);
+        part_2_breakpoints(&seeds, &mappers, trace).into()
+    }),
+];
+
+#[derive(Debug)]
+struct Mapper<'a> {
+    source: &'a str,
+    destination: &'a str,
+    ranges: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl<'a> Mapper<'a> {
+    fn new(input: &'a str) -> Self {
+        let mut lines = input.lines();
+        let mut header = lines.next().unwrap().split(' ');
+        let mut header_fields = header.next().unwrap().split("-to-");
+        let source = header_fields.next().unwrap();
+        let destination = header_fields.next().unwrap();
+
+        let mut ranges: Vec<(Range<usize>, Range<usize>)> = Vec::with_capacity(50);
+
+        for line in lines {
+            let mut fields = line.split(' ');
+            let destination_range_start = fields.next().unwrap().parse::<usize>().unwrap();
+            let source_range_start = fields.next().unwrap().parse::<usize>().unwrap();
+            let range_length = fields.next().unwrap().parse::<usize>().unwrap();
+            ranges.push((
+                Range {
+                    start: source_range_start,
+                    end: source_range_start + range_length,
+                },
+                Range {
+                    start: destination_range_start,
+                    end: destination_range_start + range_length,
+                },
+            ));
+        }
+
+        Mapper {
+            source,
+            destination,
+            ranges,
+        }
+    }
+
+    fn get_target(&self, value: usize) -> usize {
+        for (src_range, dst_range) in &self.ranges {
+            if src_range.contains(&value) {
+                return dst_range.start + (value - src_range.start);
+            }
+        }
+        value
+    }
+
+    /// Every value that `get_target` sends to `value`.
+    fn get_sources(&self, value: usize) -> Vec<usize> {
+        let mut sources = self
+            .ranges
+            .iter()
+            .filter(|(_, dst_range)| dst_range.contains(&value))
+            .map(|(src_range, dst_range)| src_range.start + (value - dst_range.start))
+            .collect::<Vec<_>>();
+        if !self
+            .ranges
+            .iter()
+            .any(|(src_range, _)| src_range.contains(&value))
+        {
+            sources.push(value);
+        }
+        sources
+    }
+}
+
+fn parse_input(input: &str) -> (Vec<usize>, FxHashMap<&str, Mapper<'_>>) {
+    let mut mappers = new_map();
+    let mut seeds: Vec<usize> = Vec::with_capacity(30);
+    let mut chunks = input.split("\n\n");
+
+    let mut seed_values = chunks.next().unwrap().split(' ');
+    seed_values.next(); // Skip the `seeds:` value
+    for seed in seed_values {
+        seeds.push(seed.parse::<usize>().unwrap());
+    }
+
+    for c in chunks {
+        let mapper = Mapper::new(c);
+        mappers.insert(mapper.source, mapper);
+    }
+    (seeds, mappers)
+}
+
+fn part_1(seeds: &[usize], mappers: &FxHashMap<&str, Mapper>, trace: &Trace) -> usize {
+    seeds
+        .par_iter()
+        .map(|seed| {
+            let mut current_value = *seed;
+            let mut current_target = "seed";
+
+            while let Some(mapper) = mappers.get(current_target) {
+                let next_value = mapper.get_target(current_value);
+                explain!(
+                    trace,
+                    "Seed {seed}: {current_target} {current_value} → {} {next_value}",
+                    mapper.destination
+                );
+                current_value = next_value;
+                current_target = mapper.destination;
+            }
+            current_value
+        })
+        .min()
+        .unwrap()
+}
+
+/// Part 1 using the range splitting of part 2, with every seed as a range of one.
+fn part_1_ranges(seeds: &[usize], mappers: &FxHashMap<&str, Mapper>, trace: &Trace) -> usize {
+    let seed_ranges = seeds.iter().flat_map(|&s| [s, 1]).collect::<Vec<_>>();
+    part_2(&seed_ranges, mappers, trace)
+}
+
+fn part_2(seeds: &[usize], mappers: &FxHashMap<&str, Mapper>, trace: &Trace) -> usize {
+    // Seed ranges are half-open, just like the ranges of the mappers.
+    let mut seed_ranges = seeds
+        .chunks(2)
+        .map(|c| c[0]..(c[0] + c[1]))
+        .collect::<Vec<Range<usize>>>();
+
+    let mut mapname = "seed";
+    while let Some(mapper) = mappers.get(mapname) {
+        let mut mapped: Vec<Range<usize>> = Vec::with_capacity(seed_ranges.len());
+        // Each mapper range cuts its overlap out of the seed ranges that are left.
+        let mut unmapped = seed_ranges;
+        for (src, dst) in &mapper.ranges {
+            let mut remaining = Vec::with_capacity(unmapped.len());
+            for seed_range in unmapped {
+                let overlap = seed_range.start.max(src.start)..seed_range.end.min(src.end);
+                if overlap.is_empty() {
+                    remaining.push(seed_range);
+                    continue;
+                }
+                mapped.push(Range {
+                    start: dst.start + (overlap.start - src.start),
+                    end: dst.start + (overlap.end - src.start),
+                });
+                if seed_range.start < overlap.start {
+                    remaining.push(seed_range.start..overlap.start);
+                }
+                if overlap.end < seed_range.end {
+                    remaining.push(overlap.end..seed_range.end);
+                }
+            }
+            unmapped = remaining;
+        }
+        // Anything no range matched keeps its value.
+        mapped.append(&mut unmapped);
+        seed_ranges = mapped;
+        explain!(
+            trace,
+            "{mapname} → {}: {} ranges, lowest starts at {}",
+            mapper.destination,
+            seed_ranges.len(),
+            seed_ranges.iter().map(|r| r.start).min().unwrap()
+        );
+        mapname = mapper.destination;
+    }
+    seed_ranges.iter().map(|r| r.start).min().unwrap()
+}
+
+/// Part 2 without splitting any ranges. Within a seed range the location only ever drops where a
+/// mapper range starts or ends, so following the seeds that land on those edges is enough.
+fn part_2_breakpoints(seeds: &[usize], mappers: &FxHashMap<&str, Mapper>, trace: &Trace) -> usize {
+    let seed_ranges = seeds
+        .chunks(2)
+        .map(|c| c[0]..(c[0] + c[1]))
+        .collect::<Vec<Range<usize>>>();
+
+    let mut chain: Vec<&Mapper> = Vec::new();
+    let mut mapname = "seed";
+    while let Some(mapper) = mappers.get(mapname) {
+        chain.push(mapper);
+        mapname = mapper.destination;
+    }
+
+    let mut candidates = seed_ranges.iter().map(|r| r.start).collect::<Vec<_>>();
+    for (i, mapper) in chain.iter().enumerate() {
+        // Pull the edges of this mapper back through the ones before it to find their seeds.
+        let mut edges = mapper
+            .ranges
+            .iter()
+            .flat_map(|(src, _)| [src.start, src.end])
+            .collect::<Vec<_>>();
+        for earlier in chain[..i].iter().rev() {
+            edges = edges
+                .into_iter()
+                .flat_map(|v| earlier.get_sources(v))
+                .collect();
+        }
+        candidates.extend(
+            edges
+                .into_iter()
+                .filter(|seed| seed_ranges.iter().any(|r| r.contains(seed))),
+        );
+    }
+    candidates.sort_unstable();
+    candidates.dedup();
+    explain!(
+        trace,
+        "{} seeds sit on the edge of a mapper range",
+        candidates.len()
+    );
+    part_1(&candidates, mappers, trace)
+}
+
+struct Repl<'a> {
+    seeds: Vec<usize>,
+    mappers: FxHashMap<&'a str, Mapper<'a>>,
+}
+
+pub fn repl(input: &str) -> Box<dyn Session + '_> {
+    let (seeds, mappers) = parse_input(input);
+    Box::new(Repl { seeds, mappers })
+}
+
+impl Repl<'_> {
+    /// Follows a value from `category` through the remaining mappers, collecting every hop.
+    fn trace<'s>(&'s self, category: &'s str, value: usize) -> Vec<(&'s str, usize)> {
+        let mut hops = vec![(category, value)];
+        let mut current = category;
+        let mut current_value = value;
+        while let Some(mapper) = self.mappers.get(current) {
+            current_value = mapper.get_target(current_value);
+            current = mapper.destination;
+            hops.push((current, current_value));
+        }
+        hops
+    }
+}
+
+impl Session for Repl<'_> {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                name: "seed",
+                usage: "seed <value>",
+                help: "Follows a seed through every mapper to its location",
+            },
+            Command {
+                name: "map",
+                usage: "map <source> <value>",
+                help: "Follows a value from any category, e.g. `map soil 81`",
+            },
+            Command {
+                name: "mappers",
+                usage: "mappers",
+                help: "Lists the chain of mappers",
+            },
+            Command {
+                name: "seeds",
+                usage: "seeds",
+                help: "Lists the seeds in the almanac",
+            },
+        ]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let hops = match command {
+            "seed" => self.trace("seed", parse_arg(args, 0)?),
+            "map" => {
+                let source = args.first().ok_or("Missing source category")?;
+                if !self.mappers.contains_key(*source) {
+                    return Err(format!("No mapper from `{source}`"));
+                }
+                self.trace(source, parse_arg(args, 1)?)
+            }
+            "mappers" => {
+                let chain = self.trace("seed", 0);
+                let names = chain.into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+                return Ok(names.join(" → "));
+            }
+            "seeds" => return Ok(format!("{:?}", self.seeds)),
+            _ => unreachable!("Unknown command"),
+        };
+        let hops = hops
+            .iter()
+            .map(|(category, value)| format!("{category} {value}"))
+            .collect::<Vec<_>>();
+        Ok(hops.join(" → "))
+    }
+}
+
+/// A `seeds:` line with pairs of numbers, then blocks of an `<a>-to-<b> map:` header followed by
+/// lines of three numbers, separated by blank lines.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut lines = input.lines().enumerate().map(|(y, line)| (y + 1, line));
+    match lines.next().map(|(_, line)| line.strip_prefix("seeds: ")) {
+        Some(Some(seeds)) => {
+            violations.extend(check_numbers(1, 8, seeds, false));
+            if !fields(seeds).count().is_multiple_of(2) {
+                let message = "seeds come in pairs of a start and a length";
+                violations.push(Violation::new(1, 8, message));
+            }
+        }
+        _ => violations.push(Violation::new(1, 1, "expected `seeds: `")),
+    }
+    let mut expect_header = false;
+    for (number, line) in lines {
+        if line.is_empty() {
+            expect_header = true;
+        } else if expect_header {
+            expect_header = false;
+            let header = line
+                .strip_suffix(" map:")
+                .and_then(|h| h.split_once("-to-"));
+            if header.is_none() {
+                let message = "expected `<source>-to-<destination> map:`";
+                violations.push(Violation::new(number, 1, message));
+            }
+        } else {
+            let count = fields(line).count();
+            if count != 3 {
+                let message = format!("expected three numbers, found {count} fields");
+                violations.push(Violation::new(number, 1, message));
+            }
+            violations.extend(check_numbers(number, 1, line, false));
+        }
+    }
+    violations
+}
+
+/// An almanac with a few seed ranges and a chain of maps whose source ranges don't overlap.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let limit = 20 * size;
+    let seeds = (0..rng.range(1, 3))
+        .map(|_| format!("{} {}", rng.range(0, limit), rng.range(1, 5 * size)))
+        .collect::<Vec<_>>();
+    let mut almanac = format!("seeds: {}", seeds.join(" "));
+
+    let categories = ["seed", "soil", "fertilizer", "water", "light", "location"];
+    for pair in categories[..=rng.range(1, 5)].windows(2) {
+        almanac.push_str(&format!("\n\n{}-to-{} map:", pair[0], pair[1]));
+        // Source ranges never overlap within a map.
+        let mut start = 0;
+        while start < limit {
+            let length = rng.range(1, size + 2);
+            if rng.range(0, 2) > 0 {
+                let destination = rng.range(0, limit);
+                almanac.push_str(&format!("\n{destination} {start} {length}"));
+            }
+            start += length;
+        }
+    }
+    almanac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::assert_agree;
+    use crate::helpers::normalize_input;
+
+    /// Follows every single seed in the ranges through the mappers.
+    fn brute_force_part_2(input: &str) -> usize {
+        let (seeds, mappers) = parse_input(input);
+        seeds
+            .chunks(2)
+            .flat_map(|c| c[0]..c[0] + c[1])
+            .map(|seed| {
+                let (mut category, mut value) = ("seed", seed);
+                while let Some(mapper) = mappers.get(category) {
+                    value = mapper.get_target(value);
+                    category = mapper.destination;
+                }
+                value
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_part_2_against_brute_force() {
+        assert_agree(
+            10,
+            100,
+            generate,
+            |input| {
+                let (seeds, mappers) = parse_input(input);
+                part_2(&seeds, &mappers, &Trace::default())
+            },
+            brute_force_part_2,
+        );
+    }
+
+    #[test]
+    fn test_part_2_breakpoints_against_brute_force() {
+        assert_agree(
+            10,
+            100,
+            generate,
+            |input| {
+                let (seeds, mappers) = parse_input(input);
+                part_2_breakpoints(&seeds, &mappers, &Trace::default())
+            },
+            brute_force_part_2,
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let (seeds, mappers) = parse_input(input);
+        assert_eq!(part_1(&seeds, &mappers, &Trace::default()), 35);
+        assert_eq!(part_1_ranges(&seeds, &mappers, &Trace::default()), 35);
+    }
+
+    /// Seed ranges that end just before, start just after or straddle the edge of a mapper range.
+    #[test]
+    fn test_part_2_range_edges() {
+        for (seeds, lowest) in [
+            ("5 5", 5),
+            ("9 1", 9),
+            ("10 1", 0),
+            ("14 2", 4),
+            ("15 3", 15),
+        ] {
+            let input = format!("seeds: {seeds}\n\nseed-to-soil map:\n0 10 5");
+            let (seeds, mappers) = parse_input(&input);
+            assert_eq!(part_2(&seeds, &mappers, &Trace::default()), lowest);
+            assert_eq!(
+                part_2_breakpoints(&seeds, &mappers, &Trace::default()),
+                lowest
+            );
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let (seeds, mappers) = parse_input(input);
+        assert_eq!(part_2(&seeds, &mappers, &Trace::default()), 46);
+        assert_eq!(part_2_breakpoints(&seeds, &mappers, &Trace::default()), 46);
+    }
+
+    /// Saved on Windows, with a BOM, CRLF line endings and trailing spaces.
+    #[test]
+    fn test_parse_messy_input() {
+        let input =
+            "\u{feff}seeds: 79 14 55 13 \r\n\r\nseed-to-soil map:\r\n50 98 2\r\n52 50 48  \r\n\r\n\
+                     soil-to-fertilizer map: \r\n0 15 37\r\n\r\n";
+        let input = normalize_input(input);
+        let (seeds, mappers) = parse_input(&input);
+        assert_eq!(seeds, [79, 14, 55, 13]);
+        assert_eq!(mappers.len(), 2);
+        assert_eq!(mappers["seed"].destination, "soil");
+        assert_eq!(
+            mappers["seed"].ranges,
+            [(98..100, 50..52), (50..98, 52..100)]
+        );
+        assert_eq!(mappers["soil"].destination, "fertilizer");
+        assert_eq!(mappers["soil"].ranges, [(15..52, 0..37)]);
+    }
+
+    #[test]
+    fn test_validate() {
+        let input =
+            "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n52 50\n\nsoil to fertilizer\n0 15 x";
+        assert_eq!(
+            validate(input),
+            [
+                Violation::new(1, 8, "seeds come in pairs of a start and a length"),
+                Violation::new(5, 1, "expected three numbers, found 2 fields"),
+                Violation::new(7, 1, "expected `<source>-to-<destination> map:`"),
+                Violation::new(8, 6, "`x` isn't a number"),
+            ]
+        );
+    }
+}
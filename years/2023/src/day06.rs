@@ -0,0 +1,228 @@
+use crate::generator::Rng;
+use crate::trace::Trace;
+use crate::validate::{check_numbers, fields, Violation};
+use crate::{DayResult, PartResult};
+use std::time::Instant;
+
+pub fn run(input: &str, _trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let races = parse(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p1 = part_1(&races).into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(&races).into();
+    let p2_duration = start.elapsed();
+    (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, _trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let races = parse(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&races).into(),
+        _ => part_2(&races).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
+type Races = Vec<Race>;
+
+#[derive(Debug)]
+struct Race {
+    time: usize,
+    distance: usize,
+}
+
+impl Race {
+    /// Distance = Time_Held * (Total_Time - Time_Held) ==> d=th-h^2 which is parabolic
+    /// 0 = -h^2 + th - d  or more commonly: `0 = -ax^2 + bx - c`
+    fn num_winning_moves(&self) -> usize {
+        let a = -1.0;
+        let b = self.time as f64;
+        let c = -(self.distance as f64);
+
+        let desc = (b.powi(2) - (4.0 * a * c)).sqrt();
+        let root_a = (-b + desc) / (2.0 * a);
+        let root_b = (-b - desc) / (2.0 * a);
+
+        // Perfect squares mess with this! Remove edge overlaps where we match the winning distance
+        let mut offset = 0.0;
+        if root_a == root_a.ceil() {
+            offset -= 1.0;
+        }
+        if root_b == root_b.floor() {
+            offset -= 1.0;
+        }
+        (root_b.floor() - root_a.ceil() + 1.0 + offset) as usize
+    }
+}
+
+fn parse(input: &str) -> Races {
+    let mut rows = input.lines();
+    let times = rows.next().unwrap().split_ascii_whitespace();
+    let distances = rows.next().unwrap().split_ascii_whitespace();
+    let mut pairs = times.zip(distances);
+
+    pairs.next(); // Skip headers
+    pairs
+        .map(|(time, distance)| Race {
+            time: time.parse::<usize>().unwrap(),
+            distance: distance.parse::<usize>().unwrap(),
+        })
+        .collect()
+}
+
+fn part_1(races: &Races) -> usize {
+    races.iter().map(|r| r.num_winning_moves()).product()
+}
+
+fn get_p2_input(races: &Races) -> Race {
+    Race {
+        time: races
+            .iter()
+            .map(|r| r.time.to_string())
+            .collect::<Vec<String>>()
+            .join("")
+            .parse::<usize>()
+            .unwrap(),
+        distance: races
+            .iter()
+            .map(|r| r.distance.to_string())
+            .collect::<Vec<String>>()
+            .join("")
+            .parse::<usize>()
+            .unwrap(),
+    }
+}
+
+fn part_2(races: &Races) -> usize {
+    let race = get_p2_input(races);
+    race.num_winning_moves()
+}
+
+/// A `Time:` and a `Distance:` line with as many numbers each.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let lines = input.lines().collect::<Vec<_>>();
+    let mut counts = Vec::new();
+    for (y, label) in ["Time:", "Distance:"].iter().enumerate() {
+        match lines.get(y).and_then(|line| line.strip_prefix(label)) {
+            Some(numbers) => {
+                violations.extend(check_numbers(y + 1, label.len() + 1, numbers, false));
+                counts.push(fields(numbers).count());
+            }
+            None => violations.push(Violation::new(y + 1, 1, format!("expected `{label}`"))),
+        }
+    }
+    if let [times, distances] = counts[..] {
+        if times != distances {
+            let message = format!("{times} times but {distances} distances");
+            violations.push(Violation::new(2, 1, message));
+        }
+    }
+    for y in 2..lines.len() {
+        violations.push(Violation::new(y + 1, 1, "expected only two lines"));
+    }
+    violations
+}
+
+/// Up to three races, including records that can't be beaten and ones that are hit exactly.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let races = (0..size.min(3))
+        .map(|_| {
+            let time = rng.range(1, 10 * size);
+            // Allow records that can't be beaten, and ones that are hit exactly.
+            (time, rng.range(0, time * time / 4 + 1))
+        })
+        .collect::<Vec<_>>();
+    let times = races
+        .iter()
+        .map(|r| format!("{:>4}", r.0))
+        .collect::<String>();
+    let distances = races
+        .iter()
+        .map(|r| format!("{:>4}", r.1))
+        .collect::<String>();
+    format!("Time:    {times}\nDistance:{distances}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::assert_agree;
+
+    /// Tries every possible hold time.
+    fn brute_force(race: &Race) -> usize {
+        (0..=race.time)
+            .filter(|held| held * (race.time - held) > race.distance)
+            .count()
+    }
+
+    #[test]
+    fn test_part_1_against_brute_force() {
+        assert_agree(
+            10,
+            100,
+            generate,
+            |input| part_1(&parse(input)),
+            |input| parse(input).iter().map(brute_force).product(),
+        );
+    }
+
+    #[test]
+    fn test_part_2_against_brute_force() {
+        assert_agree(
+            10,
+            20,
+            generate,
+            |input| part_2(&parse(input)),
+            |input| brute_force(&get_p2_input(&parse(input))),
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        let races = parse(input);
+        let result: usize = races.iter().map(|r| r.num_winning_moves()).product();
+        assert_eq!(result, 288);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        let races = parse(input);
+        assert_eq!(part_2(&races), 71503);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("Time:      7  15   30\nDistance:  9  40  200"), []);
+        assert_eq!(
+            validate("Time:      7  15   x\nDistance:  9  40\n\n1 2"),
+            [
+                Violation::new(1, 20, "`x` isn't a number"),
+                Violation::new(2, 1, "3 times but 2 distances"),
+                Violation::new(3, 1, "expected only two lines"),
+                Violation::new(4, 1, "expected only two lines"),
+            ]
+        );
+        assert_eq!(
+            validate("Distance:  9"),
+            [
+                Violation::new(1, 1, "expected `Time:`"),
+                Violation::new(2, 1, "expected `Distance:`"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use crate::day07::Card::{
+    Ace, Eight, Five, Four, Jack, Joker, King, Nine, Queen, Seven, Six, Ten, Three, Two,
+};
+use crate::day07::HandKind::{
+    FiveOfAKind, FourOfAKind, FullHouse, HighCard, OnePair, ThreeOfAKind, TwoPair,
+};
+use crate::generator::Rng;
+use crate::helpers::new_map;
+use crate::repl::{Command, Session};
+use crate::trace::Trace;
+use crate::validate::{check_numbers, fields, Violation};
+use crate::{DayResult, PartResult};
+use std::cmp::Ordering;
+
+use std::time::Instant;
+
+pub fn run(input: &str, _trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let hands: Vec<Hand> = input.lines().map(Hand::new).collect();
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p1 = part_1(&hands).into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(&hands).into();
+    let p2_duration = start.elapsed();
+    (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
+    // (None, (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, _trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let hands: Vec<Hand> = input.lines().map(Hand::new).collect();
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&hands).into(),
+        _ => part_2(&hands).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum Card {
+    Ace = 14,
+    King = 13,
+    Queen = 12,
+    Jack = 11,
+    Ten = 10,
+    Nine = 9,
+    Eight = 8,
+    Seven = 7,
+    Six = 6,
+    Five = 5,
+    Four = 4,
+    Three = 3,
+    Two = 2,
+    Joker = 1,
+}
+
+impl Card {
+    fn new(input: char) -> Self {
+        match input {
+            'A' => Ace,
+            'K' => King,
+            'Q' => Queen,
+            'J' => Jack,
+            'T' => Ten,
+            '9' => Nine,
+            '8' => Eight,
+            '7' => Seven,
+            '6' => Six,
+            '5' => Five,
+            '4' => Four,
+            '3' => Three,
+            '2' => Two,
+            _ => unreachable!("Unknown card"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum HandKind {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl HandKind {
+    fn from_cards(cards: &[Card]) -> Self {
+        let mut freq = new_map();
+        for &c in cards {
+            *freq.entry(c).or_insert(0) += 1;
+        }
+        let values = freq.into_values().collect::<Vec<usize>>();
+        let num_pairs = values.iter().fold(0, |acc, v| acc + (v == &2) as usize);
+
+        if values.contains(&5) {
+            return FiveOfAKind;
+        } else if values.contains(&4) {
+            return FourOfAKind;
+        } else if values.contains(&3) && num_pairs == 1 {
+            return FullHouse;
+        } else if values.contains(&3) {
+            return ThreeOfAKind;
+        };
+
+        match num_pairs {
+            2 => TwoPair,
+            1 => OnePair,
+            _ => HighCard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Hand {
+    cards: Vec<Card>,
+    bid: usize,
+    kind: HandKind,
+}
+
+impl Hand {
+    fn new(input: &str) -> Self {
+        let mut fields = input.split_ascii_whitespace();
+        let cards = fields
+            .next()
+            .unwrap()
+            .chars()
+            .map(Card::new)
+            .collect::<Vec<Card>>();
+        let bid = fields.next().unwrap().parse::<usize>().unwrap();
+        let kind = HandKind::from_cards(&cards);
+        Self { cards, bid, kind }
+    }
+
+    /// Jokers take the place of the card with the highest frequency and highest value if there is
+    /// a tie. So `2TJT2` would turn into `2TTT2` with these rules. The case of `oops, all jokers`
+    /// just results in a FiveOfAKind with cards valued at `1` each, as Jokers.
+    ///
+    /// WARNING: J is always treated as J, not the card it's pretending to be!
+    fn jokerize(&mut self) {
+        let mut freq = new_map();
+        for c in &self.cards {
+            *freq.entry(c).or_insert(0) += 1;
+        }
+        if let Some(5) = freq.get(&Jack) {
+            for c in &mut self.cards {
+                *c = Joker;
+                self.kind = HandKind::FiveOfAKind;
+            }
+        } else {
+            let mut tmp_cards = self.cards.clone();
+            let mut values = freq
+                .clone()
+                .iter()
+                .map(|(&&k, &v)| (k, v))
+                .filter(|(k, _)| *k != Jack)
+                .collect::<Vec<_>>();
+            values.sort_by_key(|(card, num)| (-(*num), -(*card as isize)));
+
+            for c in &mut tmp_cards {
+                if *c == Jack {
+                    *c = values.first().unwrap().0;
+                }
+            }
+            self.kind = HandKind::from_cards(&tmp_cards);
+
+            for c in &mut self.cards {
+                if *c == Jack {
+                    *c = Joker;
+                }
+            }
+        }
+    }
+
+    fn jokerized(&self) -> Self {
+        let mut hand = self.clone();
+        hand.jokerize();
+        hand
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.kind)
+            .cmp(&self.kind)
+            .then(self.cards.cmp(&other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn part_1(input: &[Hand]) -> usize {
+    let mut hands = input.iter().collect::<Vec<_>>();
+    hands.sort();
+    let mut result = 0;
+    for (rank, hand) in hands.iter().enumerate() {
+        result += hand.bid * (rank + 1);
+    }
+    result
+}
+
+fn part_2(input: &[Hand]) -> usize {
+    let mut result = 0;
+    let mut hands = input.iter().map(Hand::jokerized).collect::<Vec<_>>();
+    hands.sort();
+    for (rank, hand) in hands.iter().enumerate() {
+        result += hand.bid * (rank + 1);
+    }
+    result
+}
+
+struct Repl {
+    hands: Vec<Hand>,
+}
+
+pub fn repl(input: &str) -> Box<dyn Session + '_> {
+    Box::new(Repl {
+        hands: input.lines().map(Hand::new).collect(),
+    })
+}
+
+/// Builds a hand from user input. `Hand::new` expects five valid cards, so anything else is
+/// rejected here.
+fn parse_hand(args: &[&str]) -> Result<Hand, String> {
+    let cards = args.first().ok_or("Missing cards, e.g. `KTJJT`")?;
+    if let Some(c) = cards.chars().find(|c| !"AKQJT98765432".contains(*c)) {
+        return Err(format!("`{c}` is not a card"));
+    }
+    let count = cards.chars().count();
+    if count != 5 {
+        return Err(format!("A hand has five cards, `{cards}` has {count}"));
+    }
+    Ok(Hand::new(&format!("{cards} 0")))
+}
+
+/// Position (1-indexed) of a hand with these cards after sorting, as used for the winnings.
+fn find_rank(hands: &mut [Hand], cards: &[Card]) -> Option<usize> {
+    hands.sort();
+    hands.iter().position(|h| h.cards == cards).map(|r| r + 1)
+}
+
+impl Session for Repl {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                name: "kind",
+                usage: "kind <cards>",
+                help: "Classifies a hand with `HandKind::from_cards`",
+            },
+            Command {
+                name: "joker",
+                usage: "joker <cards>",
+                help: "Classifies a hand after `jokerize`",
+            },
+            Command {
+                name: "rank",
+                usage: "rank <cards>",
+                help: "Ranks of a hand from the input in part 1 and part 2",
+            },
+        ]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let mut hand = parse_hand(args)?;
+        match command {
+            "kind" => Ok(format!("{:?}", HandKind::from_cards(&hand.cards))),
+            "joker" => {
+                hand.jokerize();
+                Ok(format!("{:?}", hand.kind))
+            }
+            "rank" => {
+                let mut hands = self.hands.clone();
+                let p1 = find_rank(&mut hands, &hand.cards)
+                    .ok_or_else(|| format!("{} is not in the input", args[0]))?;
+                hands.iter_mut().for_each(Hand::jokerize);
+                hand.jokerize();
+                let p2 = find_rank(&mut hands, &hand.cards).unwrap();
+                Ok(format!(
+                    "Part 1: rank {p1} of {0}, Part 2: rank {p2} of {0}",
+                    hands.len()
+                ))
+            }
+            _ => unreachable!("Unknown command"),
+        }
+    }
+}
+
+/// Every line is a hand of five cards and a bid.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        let mut fields = fields(line);
+        let Some((x, hand)) = fields.next() else {
+            violations.push(Violation::new(y + 1, 1, "empty line"));
+            continue;
+        };
+        if hand.chars().count() != 5 {
+            let message = format!("a hand has five cards, found `{hand}`");
+            violations.push(Violation::new(y + 1, x + 1, message));
+        }
+        for (i, c) in hand.chars().enumerate() {
+            if !"AKQJT98765432".contains(c) {
+                let message = format!("`{c}` isn't a card");
+                violations.push(Violation::new(y + 1, x + i + 1, message));
+            }
+        }
+        match fields.next() {
+            Some((x, bid)) => violations.extend(check_numbers(y + 1, x + 1, bid, false)),
+            None => violations.push(Violation::new(y + 1, line.len() + 1, "missing bid")),
+        }
+        if let Some((x, extra)) = fields.next() {
+            violations.push(Violation::new(
+                y + 1,
+                x + 1,
+                format!("unexpected `{extra}`"),
+            ));
+        }
+    }
+    violations
+}
+
+/// `size` different hands with their bids.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut hands: Vec<String> = Vec::with_capacity(size);
+    while hands.len() < size {
+        let hand = (0..5)
+            .map(|_| rng.pick(b"AKQJT98765432") as char)
+            .collect::<String>();
+        if !hands.contains(&hand) {
+            hands.push(hand);
+        }
+    }
+    let lines = hands
+        .into_iter()
+        .map(|hand| format!("{hand} {}", rng.range(1, 1000)))
+        .collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        let hands: Vec<Hand> = input.lines().map(Hand::new).collect();
+        assert_eq!(part_1(&hands), 6440);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        let hands: Vec<Hand> = input.lines().map(Hand::new).collect();
+        assert_eq!(part_2(&hands), 5905);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("32T3K 765\nT55J5 684"), []);
+        assert_eq!(
+            validate("32T3 765\nT55X5 684 1\nKK677"),
+            [
+                Violation::new(1, 1, "a hand has five cards, found `32T3`"),
+                Violation::new(2, 4, "`X` isn't a card"),
+                Violation::new(2, 11, "unexpected `1`"),
+                Violation::new(3, 6, "missing bid"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repl_rejects_bad_hands() {
+        let mut session = repl("32T3K 765\nT55J5 684");
+        assert_eq!(
+            session.execute("joker", &["KTJJT"]),
+            Ok(String::from("FourOfAKind"))
+        );
+        assert!(session.execute("joker", &["JJJ"]).is_err());
+        assert!(session.execute("kind", &["AAAAAA"]).is_err());
+        assert!(session.execute("kind", &["AAXAA"]).is_err());
+        assert!(session.execute("rank", &[]).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::day08::Step::{Left, Right};
+use crate::generator::Rng;
+use crate::helpers::{new_map, FxHashMap};
+use crate::trace::Trace;
+use crate::validate::Violation;
+use crate::{DayResult, PartResult};
+use num::Integer;
+use rayon::prelude::*;
+use std::time::Instant;
+
+pub fn run(input: &str, _trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let (map, steps) = parse(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p1 = part_1(&map, &steps).into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(&map, &steps).into();
+    let p2_duration = start.elapsed();
+    (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, _trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let (map, steps) = parse(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&map, &steps).into(),
+        _ => part_2(&map, &steps).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
+type Map<'a> = FxHashMap<&'a str, Entry<'a>>;
+type Steps = Vec<Step>;
+
+#[derive(Debug)]
+enum Step {
+    Left,
+    Right,
+}
+
+impl Step {
+    fn from_char(c: char) -> Self {
+        match c {
+            'R' => Right,
+            'L' => Left,
+            _ => unreachable!("Unknown movement"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry<'a> {
+    source: &'a str,
+    left: &'a str,
+    right: &'a str,
+}
+
+fn parse(input: &str) -> (Map<'_>, Steps) {
+    let mut map = new_map();
+    let mut lines = input.lines();
+    let steps = lines
+        .next()
+        .unwrap()
+        .chars()
+        .map(Step::from_char)
+        .collect::<Vec<Step>>();
+    lines.next(); // Blank line;
+    for line in lines {
+        let mut fields = line.split_ascii_whitespace();
+        let source = fields.next().unwrap();
+        fields.next(); // '='
+        let left = fields.next().unwrap();
+        let right = fields.next().unwrap();
+
+        map.insert(
+            source,
+            Entry {
+                source,
+                left: left.trim_start_matches('(').trim_end_matches(','),
+                right: right.trim_end_matches(')'),
+            },
+        );
+    }
+    (map, steps)
+}
+
+/// Returns the steps required to reach the destination
+fn find_path_steps(source: &str, map: &Map, steps: &Steps, part_2: bool) -> usize {
+    let step_length = steps.len();
+    let mut current = source;
+    let mut step_cursor = 0;
+    let mut total_steps = 0;
+    while let Some(entry) = map.get(current) {
+        let destination = match &steps[step_cursor] {
+            Left => entry.left,
+            Right => entry.right,
+        };
+        total_steps += 1;
+        if (!part_2 && destination.contains("ZZZ")) || (part_2 && destination.ends_with('Z')) {
+            break;
+        }
+        current = destination;
+        step_cursor += 1;
+        step_cursor %= step_length;
+    }
+    total_steps
+}
+
+fn part_1(map: &Map, steps: &Steps) -> usize {
+    find_path_steps("AAA", map, steps, false)
+}
+
+fn part_2(map: &Map, steps: &Steps) -> usize {
+    let results = map
+        .values()
+        .filter(|&e| e.source.ends_with('A'))
+        .collect::<Vec<_>>()
+        .par_iter()
+        .map(|e| find_path_steps(e.source, map, steps, true))
+        .collect::<Vec<usize>>();
+
+    results.iter().fold(1, |acc, r| acc.lcm(r))
+}
+
+/// A line of `L` and `R` steps, a blank line, then nodes like `AAA = (BBB, CCC)`.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut lines = input.lines();
+    match lines.next() {
+        Some(steps) if !steps.is_empty() => {
+            for (x, c) in steps.char_indices().filter(|(_, c)| *c != 'L' && *c != 'R') {
+                violations.push(Violation::new(1, x + 1, format!("`{c}` isn't a step")));
+            }
+        }
+        _ => violations.push(Violation::new(1, 1, "expected the steps")),
+    }
+    if lines.next() != Some("") {
+        violations.push(Violation::new(
+            2,
+            1,
+            "expected a blank line after the steps",
+        ));
+    }
+    let is_node = |name: &str| name.bytes().all(|b| b.is_ascii_alphanumeric());
+    for (y, line) in lines.enumerate() {
+        let valid = line.len() == 16
+            && line.is_ascii()
+            && &line[3..7] == " = ("
+            && &line[10..12] == ", "
+            && line.ends_with(')')
+            && [&line[0..3], &line[7..10], &line[12..15]]
+                .into_iter()
+                .all(is_node);
+        if !valid {
+            let message = format!("expected `AAA = (BBB, CCC)`, found `{line}`");
+            violations.push(Violation::new(y + 3, 1, message));
+        }
+    }
+    violations
+}
+
+/// A network of chains from each `..A` node to its `..Z` node, which loop back to their first
+/// step so every ghost keeps passing its end. `AAA` leads to `ZZZ`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let steps = (0..rng.range(1, size + 1))
+        .map(|_| rng.pick(b"LR") as char)
+        .collect::<String>();
+    // Names in between never end in `A` or `Z`.
+    let mut counter = 0;
+    let mut name = || {
+        counter += 1;
+        let letter = |n: usize| (b'A' + (n % 26) as u8) as char;
+        let last = (b'B' + (counter % 24) as u8) as char;
+        format!(
+            "{}{}{last}",
+            letter(counter / 24 / 26),
+            letter(counter / 24)
+        )
+    };
+    let mut lines = Vec::new();
+    for ghost in 0..rng.range(1, 3) {
+        let letter = (b'A' + ghost as u8) as char;
+        let (start, end) = if ghost == 0 {
+            (String::from("AAA"), String::from("ZZZ"))
+        } else {
+            (format!("{letter}{letter}A"), format!("{letter}{letter}Z"))
+        };
+        let mut chain = vec![start];
+        chain.extend((0..rng.range(1, size)).map(|_| name()));
+        chain.push(end);
+        for (i, node) in chain.iter().enumerate() {
+            let next = chain.get(i + 1).unwrap_or(&chain[1]);
+            lines.push(format!("{node} = ({next}, {next})"));
+        }
+    }
+    format!("{steps}\n\n{}", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1_ex1() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+        let (map, steps) = parse(input);
+        let result = part_1(&map, &steps);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_part_1_ex2() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+        let (map, steps) = parse(input);
+        let result = part_1(&map, &steps);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+        let (map, steps) = parse(input);
+        let result = part_2(&map, &steps);
+        assert_eq!(result, 6);
+    }
+}
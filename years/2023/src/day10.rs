@@ -0,0 +1,595 @@
+use crate::day10::PipeKind::{
+    Ground, Horizontal, NorthEast, NorthWest, SouthEast, SouthWest, Start, Vertical,
+};
+use crate::generator::Rng;
+use crate::helpers::{new_map, FxHashMap, FxHashSet};
+use crate::trace::Trace;
+use crate::validate::{check_grid, Violation};
+use crate::{DayResult, PartResult};
+use colored::Color::{BrightBlue, Red, White};
+use colored::Colorize;
+use itertools::Itertools;
+use std::time::Instant;
+
+pub fn run(input: &str, trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let network = parse_network(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let (p1, pipe_loop) = part_1(&network);
+    let p1 = p1.into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(&network, &pipe_loop, trace).into();
+    let p2_duration = start.elapsed();
+    (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`. Part 2 still needs the loop from part 1.
+pub fn run_part(input: &str, part: usize, trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let network = parse_network(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let (p1, pipe_loop) = part_1(&network);
+    let answer = match part {
+        1 => p1.into(),
+        _ => part_2(&network, &pipe_loop, trace).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
+type Point = (isize, isize);
+type PipeNetwork = FxHashMap<Point, PipeKind>;
+/// The pipes that make up the main loop, found by part 1.
+type PipeLoop = FxHashSet<Point>;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum PipeKind {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl PipeKind {
+    fn new(c: char) -> Self {
+        match c {
+            '|' => Vertical,
+            '-' => Horizontal,
+            'L' => NorthEast,
+            'J' => NorthWest,
+            '7' => SouthWest,
+            'F' => SouthEast,
+            '.' => Ground,
+            'S' => Start,
+            _ => unreachable!("Unknown tile"),
+        }
+    }
+
+    /// Directions the pipe connects to as (row, col) offsets.
+    fn connections(self) -> &'static [(isize, isize)] {
+        match self {
+            Vertical => &[(-1, 0), (1, 0)],
+            Horizontal => &[(0, -1), (0, 1)],
+            NorthEast => &[(-1, 0), (0, 1)],
+            NorthWest => &[(-1, 0), (0, -1)],
+            SouthWest => &[(1, 0), (0, -1)],
+            SouthEast => &[(1, 0), (0, 1)],
+            Ground | Start => &[],
+        }
+    }
+}
+
+fn draw_network(network: &PipeNetwork, pipe_loop: &PipeLoop, internal: &FxHashSet<Point>) {
+    let pipes = network.iter().sorted_by_key(|(k, _v)| (k.0, k.1));
+    let mut cur_row = 0;
+    for (point @ (row, _), kind) in pipes {
+        if cur_row != *row {
+            println!();
+            cur_row = *row;
+        }
+        let c = match kind {
+            Vertical => '║',
+            Horizontal => '═',
+            NorthEast => '╚',
+            NorthWest => '╝',
+            SouthWest => '╗',
+            SouthEast => '╔',
+            Ground => ' ',
+            Start => '◎',
+        };
+        if pipe_loop.contains(point) {
+            print!("{}", c.to_string().color(BrightBlue));
+        } else if internal.contains(point) {
+            print!("{}", "▒".to_string().color(Red));
+        } else {
+            print!("{}", c.to_string().color(White));
+        }
+    }
+    println!();
+}
+
+fn parse_network(input: &str) -> PipeNetwork {
+    let mut network: PipeNetwork = new_map();
+    for (row, line) in input.lines().enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            let p: Point = (row as isize, col as isize);
+            network.insert(p, PipeKind::new(c));
+        }
+    }
+    network
+}
+
+fn travel_pipe(network: &PipeNetwork, pipe_loop: &mut PipeLoop, point: &Point) -> Option<Point> {
+    let current_kind = *network.get(point).unwrap();
+    let mut target = None;
+    // Eastwards
+    if let Some(kind) = network.get(&(point.0, point.1 + 1)) {
+        if !pipe_loop.contains(&(point.0, point.1 + 1)) {
+            match (current_kind, kind) {
+                (Horizontal, Horizontal) | (Horizontal, NorthWest) | (Horizontal, SouthWest) => {
+                    pipe_loop.insert((point.0, point.1 + 1));
+                    target = Some((point.0, point.1 + 1))
+                }
+                (NorthEast, Horizontal) | (NorthEast, SouthWest) | (NorthEast, NorthWest) => {
+                    pipe_loop.insert((point.0, point.1 + 1));
+                    target = Some((point.0, point.1 + 1))
+                }
+                (SouthEast, Horizontal) | (SouthEast, SouthWest) | (SouthEast, NorthWest) => {
+                    pipe_loop.insert((point.0, point.1 + 1));
+                    target = Some((point.0, point.1 + 1))
+                }
+                (Start, Horizontal) | (Start, SouthWest) | (Start, NorthWest) => {
+                    pipe_loop.insert((point.0, point.1 + 1));
+                    target = Some((point.0, point.1 + 1))
+                }
+                (_, _) => (),
+            }
+        }
+    }
+    // Westwards
+    if let Some(kind) = network.get(&(point.0, point.1 - 1)) {
+        if !pipe_loop.contains(&(point.0, point.1 - 1)) {
+            match (current_kind, kind) {
+                (Horizontal, Horizontal) | (Horizontal, NorthEast) | (Horizontal, SouthEast) => {
+                    pipe_loop.insert((point.0, point.1 - 1));
+                    target = Some((point.0, point.1 - 1))
+                }
+                (NorthWest, Horizontal) | (NorthWest, NorthEast) | (NorthWest, SouthEast) => {
+                    pipe_loop.insert((point.0, point.1 - 1));
+                    target = Some((point.0, point.1 - 1))
+                }
+                (SouthWest, Horizontal) | (SouthWest, NorthEast) | (SouthWest, SouthEast) => {
+                    pipe_loop.insert((point.0, point.1 - 1));
+                    target = Some((point.0, point.1 - 1))
+                }
+                (Start, Horizontal) | (Start, NorthEast) | (Start, SouthEast) => {
+                    pipe_loop.insert((point.0, point.1 - 1));
+                    target = Some((point.0, point.1 - 1))
+                }
+                (_, _) => (),
+            }
+        }
+    }
+    // Northwards
+    if let Some(kind) = network.get(&(point.0 - 1, point.1)) {
+        if !pipe_loop.contains(&(point.0 - 1, point.1)) {
+            match (current_kind, kind) {
+                (Vertical, Vertical) | (Vertical, SouthWest) | (Vertical, SouthEast) => {
+                    pipe_loop.insert((point.0 - 1, point.1));
+                    target = Some((point.0 - 1, point.1))
+                }
+                (NorthEast, Vertical) | (NorthEast, SouthWest) | (NorthEast, SouthEast) => {
+                    pipe_loop.insert((point.0 - 1, point.1));
+                    target = Some((point.0 - 1, point.1))
+                }
+                (NorthWest, Vertical) | (NorthWest, SouthWest) | (NorthWest, SouthEast) => {
+                    pipe_loop.insert((point.0 - 1, point.1));
+                    target = Some((point.0 - 1, point.1))
+                }
+                (Start, Vertical) | (Start, SouthWest) | (Start, SouthEast) => {
+                    pipe_loop.insert((point.0 - 1, point.1));
+                    target = Some((point.0 - 1, point.1))
+                }
+                (_, _) => (),
+            }
+        }
+    }
+    // Southwards
+    if let Some(kind) = network.get(&(point.0 + 1, point.1)) {
+        if !pipe_loop.contains(&(point.0 + 1, point.1)) {
+            match (current_kind, kind) {
+                (Vertical, Vertical) | (Vertical, NorthEast) | (Vertical, NorthWest) => {
+                    pipe_loop.insert((point.0 + 1, point.1));
+                    target = Some((point.0 + 1, point.1))
+                }
+                (SouthWest, Vertical) | (SouthWest, NorthEast) | (SouthWest, NorthWest) => {
+                    pipe_loop.insert((point.0 + 1, point.1));
+                    target = Some((point.0 + 1, point.1))
+                }
+                (SouthEast, Vertical) | (SouthEast, NorthEast) | (SouthEast, NorthWest) => {
+                    pipe_loop.insert((point.0 + 1, point.1));
+                    target = Some((point.0 + 1, point.1))
+                }
+                (Start, Vertical) | (Start, NorthEast) | (Start, NorthWest) => {
+                    pipe_loop.insert((point.0 + 1, point.1));
+                    target = Some((point.0 + 1, point.1))
+                }
+                (_, _) => (),
+            }
+        }
+    }
+    target
+}
+
+/// Also returns the pipes of the loop, which part 2 needs.
+fn part_1(network: &PipeNetwork) -> (isize, PipeLoop) {
+    let (&start, _) = network
+        .iter()
+        .find(|(_, &kind)| kind == Start)
+        .expect("Couldn't find starting location.");
+    let mut pipe_loop = PipeLoop::default();
+    pipe_loop.insert(start);
+    let mut current_hop = start;
+    let mut total_steps = 2;
+    while let Some(x) = travel_pipe(network, &mut pipe_loop, &current_hop) {
+        total_steps += 1;
+        current_hop = x;
+    }
+    (total_steps / 2, pipe_loop)
+}
+
+fn part_2(network: &PipeNetwork, pipe_loop: &PipeLoop, trace: &Trace) -> usize {
+    let (&start, _) = network
+        .iter()
+        .find(|(_, &kind)| kind == Start)
+        .expect("Couldn't find starting location.");
+    // Crossings depend on the shape of the start too.
+    let start_kind = start_kind(network, pipe_loop, start);
+    let kind_at = |point: &Point| match network.get(point) {
+        Some(Start) => Some(start_kind),
+        kind => kind.copied(),
+    };
+    let pipes = network.iter().sorted_by_key(|(k, _v)| (k.0, k.1));
+    let mut internal = FxHashSet::default();
+    let mut cur_row: isize = 0;
+
+    let mut outside_edge = true;
+
+    for (point @ (row, col), _) in pipes {
+        if *row > cur_row {
+            cur_row = *row;
+            outside_edge = true;
+        }
+        let mut col_offset: isize = 1;
+        let mut crossings = 0;
+        let mut prev_pipe_visited = pipe_loop.contains(point);
+        let mut prev_pipe_kind = kind_at(point).unwrap();
+        while let Some(next_kind) = kind_at(&(cur_row, col + col_offset)) {
+            let next_visited = pipe_loop.contains(&(cur_row, col + col_offset));
+            // If we hit an `╔` or `╚` pipe then it's possible to travel down the network for a bit
+            // before leaving the intersection again since we are scanning eastwards. Only by
+            // hitting a `╗` or `╝` pipe can we consider the horizontal span finished.
+            match next_kind {
+                NorthWest | SouthWest
+                    if next_visited
+                        && prev_pipe_visited
+                        && ((prev_pipe_kind == NorthEast && next_kind == NorthWest)
+                            || (prev_pipe_kind == SouthEast && next_kind == SouthWest)) =>
+                {
+                    crossings += 1
+                }
+                NorthEast | SouthEast | Vertical if next_visited => crossings += 1,
+                _ => (),
+            }
+            prev_pipe_visited = next_visited;
+            if next_kind != Horizontal {
+                prev_pipe_kind = next_kind;
+            }
+            col_offset += 1;
+        }
+        let visited = pipe_loop.contains(point);
+        if visited {
+            outside_edge = false;
+        }
+        if crossings % 2 != 0 && !visited && !outside_edge {
+            internal.insert(*point);
+        }
+    }
+    if trace.enabled() {
+        draw_network(network, pipe_loop, &internal);
+    }
+    internal.len()
+}
+
+/// Directions a tile connects to as (row, col) offsets.
+fn connections(tile: u8) -> &'static [(isize, isize)] {
+    PipeKind::new(tile as char).connections()
+}
+
+/// The pipe under `S`, which connects to the two loop pipes that connect back to it.
+fn start_kind(network: &PipeNetwork, pipe_loop: &PipeLoop, start: Point) -> PipeKind {
+    let links_back = |&(dr, dc): &(isize, isize)| {
+        let neighbour = (start.0 + dr, start.1 + dc);
+        pipe_loop.contains(&neighbour) && network[&neighbour].connections().contains(&(-dr, -dc))
+    };
+    [
+        Vertical, Horizontal, NorthEast, NorthWest, SouthWest, SouthEast,
+    ]
+    .into_iter()
+    .find(|kind| kind.connections().iter().all(links_back))
+    .expect("The start isn't on a loop.")
+}
+
+/// A grid of pipes with exactly one `S`.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = check_grid(input, "|-LJ7F.S");
+    let starts = input.lines().enumerate().flat_map(|(y, line)| {
+        line.char_indices()
+            .filter(|(_, c)| *c == 'S')
+            .map(move |(x, _)| (y, x))
+    });
+    let mut count = 0;
+    for (y, x) in starts {
+        count += 1;
+        if count > 1 {
+            violations.push(Violation::new(y + 1, x + 1, "another `S`"));
+        }
+    }
+    if count == 0 {
+        violations.push(Violation::new(1, 1, "there is no `S`"));
+    }
+    violations
+}
+
+/// A grid of random pipes around a loop that follows the outline of a shape made of
+/// overlapping columns.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (shape_height, shape_width) = (rng.range(1, size), rng.range(1, size));
+    let mut top = rng.range(0, shape_height - 1);
+    let mut bottom = rng.range(top + 1, shape_height);
+    let mut columns = vec![(top, bottom)];
+    for _ in 1..shape_width {
+        let next_top = rng.range(0, bottom - 1);
+        let next_bottom = rng.range((top + 1).max(next_top + 1), shape_height);
+        (top, bottom) = (next_top, next_bottom);
+        columns.push((top, bottom));
+    }
+    let inside = |r: isize, c: isize| {
+        c >= 0
+            && (c as usize) < columns.len()
+            && r >= columns[c as usize].0 as isize
+            && r < columns[c as usize].1 as isize
+    };
+
+    // The outline runs between the corners of the cells, so every corner becomes a tile.
+    let margin = (rng.range(0, 2), rng.range(0, 2));
+    let height = shape_height + 1 + margin.0 + rng.range(0, 2);
+    let width = shape_width + 1 + margin.1 + rng.range(0, 2);
+    let mut grid = vec![vec![b'.'; width]; height];
+    let mut outline = Vec::new();
+    for (r, row) in grid.iter_mut().enumerate() {
+        for (c, tile) in row.iter_mut().enumerate() {
+            let (y, x) = (
+                (r - margin.0.min(r)) as isize,
+                (c - margin.1.min(c)) as isize,
+            );
+            let on_shape = r >= margin.0 && c >= margin.1;
+            // Whether the cells around this corner are inside, clockwise from top left.
+            let around = [
+                on_shape && inside(y - 1, x - 1),
+                on_shape && inside(y - 1, x),
+                on_shape && inside(y, x),
+                on_shape && inside(y, x - 1),
+            ];
+            let north = around[0] != around[1];
+            let east = around[1] != around[2];
+            let south = around[2] != around[3];
+            let west = around[3] != around[0];
+            *tile = match (north, east, south, west) {
+                (true, false, true, false) => b'|',
+                (false, true, false, true) => b'-',
+                (true, true, false, false) => b'L',
+                (true, false, false, true) => b'J',
+                (false, false, true, true) => b'7',
+                (false, true, true, false) => b'F',
+                _ => rng.pick(b"|-LJ7F..."),
+            };
+            if north || east || south || west {
+                outline.push((r, c));
+            }
+        }
+    }
+    let start = outline[rng.range(0, outline.len() - 1)];
+    grid[start.0][start.1] = b'S';
+    // Stray pipes next to the start mustn't look like they belong to the loop.
+    for (dr, dc) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+        let (r, c) = (start.0 as isize + dr, start.1 as isize + dc);
+        if r < 0 || c < 0 || r as usize >= height || c as usize >= width {
+            continue;
+        }
+        let (r, c) = (r as usize, c as usize);
+        let on_loop = outline.contains(&(r, c));
+        if !on_loop && connections(grid[r][c]).contains(&(-dr, -dc)) {
+            grid[r][c] = b'.';
+        }
+    }
+    let lines = grid
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::assert_agree;
+
+    /// Walks the loop, draws it at three times the size and flood fills the outside. Tiles whose
+    /// centre isn't reached are enclosed.
+    fn brute_force_part_2(input: &str) -> usize {
+        let grid = input.lines().map(|l| l.as_bytes()).collect::<Vec<_>>();
+        let (height, width) = (grid.len() as isize, grid[0].len() as isize);
+        let tile = |(r, c): Point| {
+            if r < 0 || c < 0 || r >= height || c >= width {
+                b'.'
+            } else {
+                grid[r as usize][c as usize]
+            }
+        };
+        let start = (0..height)
+            .flat_map(|r| (0..width).map(move |c| (r, c)))
+            .find(|&p| tile(p) == b'S')
+            .unwrap();
+        // The start connects to every neighbour that connects back to it.
+        let start_connections = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter(|&(dr, dc)| {
+                connections(tile((start.0 + dr, start.1 + dc))).contains(&(-dr, -dc))
+            })
+            .collect::<Vec<_>>();
+        let links = |p: Point| match tile(p) {
+            b'S' => start_connections.clone(),
+            t => connections(t).to_vec(),
+        };
+
+        let mut big = vec![vec![false; 3 * width as usize]; 3 * height as usize];
+        let (mut previous, mut current) = (start, start);
+        loop {
+            let (r, c) = (3 * current.0 as usize + 1, 3 * current.1 as usize + 1);
+            big[r][c] = true;
+            for (dr, dc) in links(current) {
+                big[(r as isize + dr) as usize][(c as isize + dc) as usize] = true;
+            }
+            let next = links(current)
+                .into_iter()
+                .map(|(dr, dc)| (current.0 + dr, current.1 + dc))
+                .find(|&n| n != previous)
+                .unwrap();
+            (previous, current) = (current, next);
+            if current == start {
+                break;
+            }
+        }
+
+        let (big_height, big_width) = (big.len(), big[0].len());
+        let mut outside = vec![vec![false; big_width]; big_height];
+        let mut queue = vec![(0, 0)];
+        while let Some((r, c)) = queue.pop() {
+            if big[r][c] || outside[r][c] {
+                continue;
+            }
+            outside[r][c] = true;
+            if r > 0 {
+                queue.push((r - 1, c));
+            }
+            if c > 0 {
+                queue.push((r, c - 1));
+            }
+            if r + 1 < big_height {
+                queue.push((r + 1, c));
+            }
+            if c + 1 < big_width {
+                queue.push((r, c + 1));
+            }
+        }
+        (0..height as usize)
+            .flat_map(|r| (0..width as usize).map(move |c| (3 * r + 1, 3 * c + 1)))
+            .filter(|&(r, c)| !big[r][c] && !outside[r][c])
+            .count()
+    }
+
+    #[test]
+    fn test_brute_force_part_2() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        assert_eq!(brute_force_part_2(input), 10);
+    }
+
+    #[test]
+    fn test_part_2_against_brute_force() {
+        assert_agree(
+            8,
+            30,
+            generate,
+            |input| {
+                let network = parse_network(input);
+                let (_, pipe_loop) = part_1(&network);
+                part_2(&network, &pipe_loop, &Trace::default())
+            },
+            brute_force_part_2,
+        );
+    }
+
+    #[test]
+    fn test_part_1_sample_1() {
+        let input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+        let network = parse_network(input);
+        assert_eq!(part_1(&network).0, 4);
+    }
+
+    #[test]
+    fn test_part_1_sample_2() {
+        let input = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+        let network = parse_network(input);
+        assert_eq!(part_1(&network).0, 8);
+    }
+
+    #[test]
+    fn test_part_2_example_1() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        let network = parse_network(input);
+        let (_, pipe_loop) = part_1(&network);
+        assert_eq!(part_2(&network, &pipe_loop, &Trace::default()), 10);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(".S-7\n.|.|\n.L-J"), []);
+        assert_eq!(
+            validate(".S-7\n.|\n.L-S"),
+            [
+                Violation::new(2, 3, "row is 2 wide, but the first row is 4"),
+                Violation::new(3, 4, "another `S`"),
+            ]
+        );
+        assert_eq!(
+            validate("..\n.."),
+            [Violation::new(1, 1, "there is no `S`")]
+        );
+    }
+}
@@ -1,19 +1,32 @@
-use crate::DayResult;
+use crate::generator::Rng;
+use crate::trace::Trace;
+use crate::validate::{check_grid, Violation};
+use crate::{DayResult, PartResult};
 use itertools::Itertools;
 use num::abs;
 use std::time::Instant;
 
-pub fn run(input: &str) -> DayResult {
+pub fn run(input: &str, _trace: &Trace) -> DayResult {
     let start = Instant::now();
-    let p1 = part_1(input).to_string();
+    let p1 = part_1(input).into();
     let p1_duration = start.elapsed();
 
     let start = Instant::now();
-    let p2 = part_2(input, 1_000_000 - 1).to_string();
+    let p2 = part_2(input, 1_000_000 - 1).into();
     let p2_duration = start.elapsed();
     (None, (p1, p1_duration), (p2, p2_duration))
 }
 
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, _trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(input).into(),
+        _ => part_2(input, 1_000_000 - 1).into(),
+    };
+    (None, (answer, start.elapsed()))
+}
+
 type Point = (isize, isize);
 type Image = Vec<Point>;
 
@@ -95,6 +108,26 @@ fn part_2(input: &str, steps: isize) -> isize {
     calc_distances(&image)
 }
 
+/// A grid of space and galaxies.
+pub fn validate(input: &str) -> Vec<Violation> {
+    check_grid(input, ".#")
+}
+
+/// A square image with galaxies in roughly one cell in ten, and always at least one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size + 2;
+    let mut image = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| rng.pick(b"#.........") as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    let row = rng.range(0, side - 1);
+    image[row].replace_range(0..1, "#");
+    image.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,323 @@
+use crate::day14::Direction::{East, North, South, West};
+use crate::day14::Rock::Round;
+use crate::generator::Rng;
+use crate::helpers::{new_map, FxHashMap};
+use crate::trace::{explain, Trace};
+use crate::validate::{check_grid, Violation};
+use crate::{DayResult, PartResult};
+use colored::Colorize;
+use std::time::Instant;
+
+pub fn run(input: &str, trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let (platform, max_point) = parse_input(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p1 = part_1(&platform, max_point, trace).into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(&platform, max_point, trace).into();
+    let p2_duration = start.elapsed();
+    (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let (platform, max_point) = parse_input(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&platform, max_point, trace).into(),
+        _ => part_2(&platform, max_point, trace).into(),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
+type Platform = FxHashMap<Point, Rock>;
+type Point = (isize, isize);
+
+#[derive(Debug, Clone, PartialEq, Hash)]
+enum Rock {
+    Cube,
+    Round,
+}
+
+impl Rock {
+    fn new(input: char) -> Option<Self> {
+        match input {
+            'O' => Some(Rock::Round),
+            '#' => Some(Rock::Cube),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the rocks and the bottom right corner of the platform.
+fn parse_input(input: &str) -> (Platform, Point) {
+    let mut platform: Platform = new_map();
+    let mut max_point = (0, 0);
+    for (row, line) in input.lines().enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            max_point = (row as isize, max_point.1.max(col as isize));
+            if let Some(rock) = Rock::new(c) {
+                platform.insert((row as isize, col as isize), rock);
+            }
+        }
+    }
+    (platform, max_point)
+}
+
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+fn draw_platform(platform: &Platform, max_point: Point) {
+    for row in 0..=max_point.0 {
+        for col in 0..=max_point.1 {
+            if let Some(rock) = platform.get(&(row, col)) {
+                let output = match rock {
+                    Rock::Cube => "#".bright_yellow(),
+                    Rock::Round => "O".bright_purple(),
+                };
+                print!("{}", output);
+            } else {
+                print!(" ");
+            }
+        }
+        println!();
+    }
+}
+
+/// Simulates shifting the platform a certain direction. Returns the number of stones that moved.
+#[inline(always)]
+fn tick_platform(platform: &mut Platform, direction: &Direction, max_point: Point) -> usize {
+    let mut moved_stones = 0;
+
+    let rock_offset: Point = match direction {
+        North => (-1, 0),
+        South => (1, 0),
+        East => (0, 1),
+        West => (0, -1),
+    };
+    // I thought it would be faster to flip around row/column order based on direction but that is
+    // actually ~30% slower when running the full day. There's optimizations here somewhere though.
+    for row in 0..=max_point.0 {
+        for col in 0..=max_point.1 {
+            let target = (row + rock_offset.0, col + rock_offset.1);
+            // Rocks can not slide off of the platform.
+            if target.0 < 0 || target.1 < 0 || target.0 > max_point.0 || target.1 > max_point.1 {
+                continue;
+            }
+            if platform.contains_key(&(row, col))
+                && !platform.contains_key(&target)
+                && *platform.get(&(row, col)).unwrap() == Round
+            {
+                let rock = platform.remove(&(row, col)).unwrap();
+                platform.insert(target, rock);
+                moved_stones += 1;
+            }
+        }
+    }
+    moved_stones
+}
+
+fn calc_load(platform: &Platform, max_point: Point) -> isize {
+    let mut load = 0;
+    for row in 0..=max_point.0 {
+        for col in 0..=max_point.1 {
+            if let Some(rock) = platform.get(&(row, col)) {
+                load += match rock {
+                    Rock::Cube => 0,
+                    Rock::Round => max_point.0 - row + 1,
+                };
+            }
+        }
+    }
+    load
+}
+
+fn part_1(platform: &Platform, max_point: Point, trace: &Trace) -> isize {
+    let mut platform = platform.clone();
+    loop {
+        let moved_stones = tick_platform(&mut platform, &North, max_point);
+        explain!(trace, "Moved {moved_stones} stones north");
+        if moved_stones == 0 {
+            break;
+        }
+    }
+    if trace.enabled() {
+        draw_platform(&platform, max_point);
+    }
+    calc_load(&platform, max_point)
+}
+
+#[inline(always)]
+fn roll_rocks_until_stopped(platform: &mut Platform, direction: &Direction, max_point: Point) {
+    loop {
+        if tick_platform(platform, direction, max_point) == 0 {
+            break;
+        }
+    }
+}
+
+fn part_2(platform: &Platform, max_point: Point, trace: &Trace) -> isize {
+    spin(platform, max_point, 1_000_000_000, trace)
+}
+
+/// Load after `cycles` spin cycles, skipping ahead once the platform starts repeating itself.
+fn spin(platform: &Platform, max_point: Point, cycles: usize, trace: &Trace) -> isize {
+    let mut platform = platform.clone();
+    // The platform after each number of spins, starting with none.
+    let mut seen_positions: Vec<Platform> = Vec::with_capacity(20_000);
+    seen_positions.push(platform.clone());
+
+    while seen_positions.len() <= cycles {
+        roll_rocks_until_stopped(&mut platform, &North, max_point);
+        roll_rocks_until_stopped(&mut platform, &West, max_point);
+        roll_rocks_until_stopped(&mut platform, &South, max_point);
+        roll_rocks_until_stopped(&mut platform, &East, max_point);
+        if let Some(cycle_start) = seen_positions.iter().position(|p| p == &platform) {
+            let cycle_length = seen_positions.len() - cycle_start;
+            let num_remaining_steps = (cycles - cycle_start) % cycle_length;
+            explain!(
+                trace,
+                "Cycle of {cycle_length} spins after {cycle_start}, \
+                 {num_remaining_steps} spins left over"
+            );
+            platform = seen_positions.swap_remove(cycle_start + num_remaining_steps);
+            break;
+        }
+        seen_positions.push(platform.clone());
+    }
+    if trace.enabled() {
+        draw_platform(&platform, max_point);
+    }
+    calc_load(&platform, max_point)
+}
+
+/// A grid of round and cube-shaped rocks.
+pub fn validate(input: &str) -> Vec<Violation> {
+    check_grid(input, ".#O")
+}
+
+/// A platform of random rocks, not necessarily square.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (height, width) = (rng.range(1, size + 1), rng.range(1, size + 1));
+    let rows = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| rng.pick(&['O', 'O', '#', '.', '.', '.']))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::assert_agree;
+
+    /// Spins a grid of bytes one cycle at a time.
+    fn brute_force_spin(input: &str, cycles: usize) -> usize {
+        let mut grid = input
+            .lines()
+            .map(|l| l.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        for _ in 0..cycles {
+            for _ in 0..4 {
+                // Roll north, then turn the grid clockwise so the next direction is north.
+                for col in 0..grid[0].len() {
+                    let mut free = 0;
+                    for row in 0..grid.len() {
+                        match grid[row][col] {
+                            b'#' => free = row + 1,
+                            b'O' => {
+                                grid[row][col] = b'.';
+                                grid[free][col] = b'O';
+                                free += 1;
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                grid = (0..grid[0].len())
+                    .map(|col| grid.iter().rev().map(|row| row[col]).collect())
+                    .collect();
+            }
+        }
+        let rows = grid.len();
+        grid.iter()
+            .enumerate()
+            .map(|(row, line)| line.iter().filter(|&&c| c == b'O').count() * (rows - row))
+            .sum()
+    }
+
+    #[test]
+    fn test_spin_against_brute_force() {
+        for cycles in [100, 101, 137] {
+            assert_agree(
+                6,
+                20,
+                generate,
+                |input| {
+                    let (platform, max_point) = parse_input(input);
+                    spin(&platform, max_point, cycles, &Trace::default()) as usize
+                },
+                |input| brute_force_spin(input, cycles),
+            );
+        }
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let (platform, max_point) = parse_input(input);
+        assert_eq!(part_1(&platform, max_point, &Trace::default()), 136);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let (platform, max_point) = parse_input(input);
+        assert_eq!(part_2(&platform, max_point, &Trace::default()), 64);
+    }
+
+    #[test]
+    fn test_tall_platform() {
+        let (platform, max_point) = parse_input("#\n.\nO");
+        assert_eq!(part_1(&platform, max_point, &Trace::default()), 2);
+    }
+
+    /// A platform that is back where it started after one spin used to loop forever.
+    #[test]
+    fn test_part_2_repeats_from_start() {
+        let (platform, max_point) = parse_input("O");
+        assert_eq!(part_2(&platform, max_point, &Trace::default()), 1);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::generator::Rng;
+use crate::trace::Trace;
+use crate::validate::Violation;
+use crate::{DayResult, PartResult};
+use std::collections::LinkedList;
+use std::time::Instant;
+
+pub fn run(input: &str, _trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let p1 = part_1(input).into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(input).into();
+    let p2_duration = start.elapsed();
+    (None, (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, _trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(input).into(),
+        _ => part_2(input).into(),
+    };
+    (None, (answer, start.elapsed()))
+}
+
+fn hash_string(input: &str) -> usize {
+    let mut current_value = 0;
+    for c in input.chars() {
+        current_value += c as usize;
+        current_value *= 17;
+        current_value %= 256;
+    }
+    current_value
+}
+
+#[derive(Debug, Clone)]
+struct Lens<'a> {
+    label: &'a str,
+    focal_length: isize,
+}
+
+impl<'a> Lens<'a> {
+    fn new(input: &'a str) -> Self {
+        let mut split = input.split(['-', '=']);
+        let label = split.nth(0).unwrap();
+        let focal_field = split.nth(0).unwrap_or("");
+
+        if let Ok(focal_length) = focal_field.parse::<isize>() {
+            Self {
+                label,
+                focal_length,
+            }
+        } else {
+            Self {
+                label,
+                focal_length: -1,
+            }
+        }
+    }
+}
+
+fn part_1(input: &str) -> usize {
+    input.split(',').map(hash_string).sum()
+}
+
+#[inline(always)]
+fn calc_box_power(lenses: &LinkedList<Lens>, box_number: usize) -> usize {
+    lenses
+        .iter()
+        .enumerate()
+        .map(|(slot, lens)| {
+            let slot = slot + 1; // Lens slots are 1-indexed
+            (1 + box_number) * slot * lens.focal_length as usize
+        })
+        .sum()
+}
+
+fn part_2(input: &str) -> usize {
+    let mut boxes: Vec<LinkedList<Lens>> = Vec::with_capacity(257);
+    // Boxes are 0-indexed.
+    for _ in 0..256 {
+        boxes.push(LinkedList::default());
+    }
+
+    for line in input.split(',') {
+        let lens = Lens::new(line);
+        let target_box = boxes.get_mut(hash_string(lens.label)).unwrap();
+        if line.contains('-') {
+            target_box
+                .extract_if(|l| l.label == lens.label)
+                .for_each(drop);
+        } else if line.contains('=') {
+            if let Some(lens_to_replace) = target_box.iter_mut().find(|l| l.label == lens.label) {
+                *lens_to_replace = lens;
+            } else {
+                target_box.push_back(lens);
+            }
+        }
+    }
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(box_number, lenses)| calc_box_power(lenses, box_number))
+        .sum()
+}
+
+/// One line of steps separated by commas, each a label followed by `-` or `=` and a focal length.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        if y > 0 {
+            violations.push(Violation::new(y + 1, 1, "the steps should be on one line"));
+        }
+        let mut column = 1;
+        for step in line.split(',') {
+            let label = step
+                .find(|c: char| !c.is_ascii_lowercase())
+                .unwrap_or(step.len());
+            let operation = &step.as_bytes()[label..];
+            if label == 0 || !matches!(operation, [b'-'] | [b'=', b'1'..=b'9']) {
+                let message = format!("expected `<label>-` or `<label>=<1-9>`, found `{step}`");
+                violations.push(Violation::new(y + 1, column, message));
+            }
+            column += step.len() + 1;
+        }
+    }
+    violations
+}
+
+/// `size` steps on short labels, so that lenses often share a box or a label.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let steps = (0..size)
+        .map(|_| {
+            let label = (0..rng.range(1, 3))
+                .map(|_| rng.pick(b"abcdefgh") as char)
+                .collect::<String>();
+            if rng.range(0, 2) == 0 {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.range(1, 9))
+            }
+        })
+        .collect::<Vec<_>>();
+    steps.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::normalize_input;
+
+    #[test]
+    fn test_part_1() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(part_1(input), 1320);
+    }
+
+    /// Every byte goes into the hash, so a BOM, `\r` or space left on a step would change it.
+    #[test]
+    fn test_parse_messy_input() {
+        let input =
+            normalize_input("\u{feff}rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7 \r\n\r\n");
+        let steps = input.split(',').collect::<Vec<_>>();
+        assert_eq!((steps[0], steps[10]), ("rn=1", "ot=7"));
+        let lens = Lens::new(steps[10]);
+        assert_eq!((lens.label, lens.focal_length), ("ot", 7));
+        assert_eq!(part_1(&input), 1320);
+        assert_eq!(part_2(&input), 145);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(part_2(input), 145);
+    }
+}
@@ -0,0 +1,592 @@
+use crate::answer::Answer;
+use crate::day19::Action::{Accept, Reject, SendToWorkflow};
+use crate::day19::PartCmp::{GreaterThan, LessThan};
+use crate::day19::PartKind::{Aerodynamic, ExtremelyCoolLooking, Musical, Shiny};
+use crate::generator::Rng;
+use crate::helpers::{new_map, FxHashMap, FxHashSet};
+use crate::repl::{Command, Session};
+use crate::trace::Trace;
+use crate::validate::{check_numbers, Violation};
+use crate::{DayResult, PartResult};
+use std::fmt;
+use std::time::Instant;
+
+pub fn run(input: &str, trace: &Trace) -> DayResult {
+    let start = Instant::now();
+    let (workflows, parts) = parse_input(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p1 = part_1(&workflows, &parts, trace).into();
+    let p1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let p2 = part_2(input);
+    let p2_duration = start.elapsed();
+    (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
+}
+
+/// Like `run`, but only solves `part`.
+pub fn run_part(input: &str, part: usize, trace: &Trace) -> PartResult {
+    let start = Instant::now();
+    let (workflows, parts) = parse_input(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => part_1(&workflows, &parts, trace).into(),
+        _ => part_2(input),
+    };
+    (Some(parse_duration), (answer, start.elapsed()))
+}
+
+#[derive(Debug)]
+enum PartKind {
+    ExtremelyCoolLooking,
+    Musical,
+    Aerodynamic,
+    Shiny,
+}
+
+impl PartKind {
+    fn new(input: &str) -> Self {
+        match input.chars().collect::<Vec<char>>().first().unwrap() {
+            'x' => ExtremelyCoolLooking,
+            'm' => Musical,
+            'a' => Aerodynamic,
+            's' => Shiny,
+            _ => unreachable!("Unknown part kind"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum PartCmp {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Action<'a> {
+    Accept,
+    Reject,
+    SendToWorkflow { workflow: &'a str },
+}
+
+impl<'a> Action<'a> {
+    fn new(input: &'a str) -> Self {
+        if input.eq("A") {
+            Accept
+        } else if input.eq("R") {
+            Reject
+        } else {
+            SendToWorkflow { workflow: input }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RuleComparison {
+    part_kind: PartKind,
+    part_cmp: PartCmp,
+    value: isize,
+}
+
+#[derive(Debug)]
+struct Rule<'a> {
+    rule_comparison: Option<RuleComparison>,
+    action: Action<'a>,
+}
+
+#[derive(Debug)]
+struct Workflow<'a> {
+    name: &'a str,
+    rules: Vec<Rule<'a>>,
+}
+
+type Workflows<'a> = FxHashMap<&'a str, Workflow<'a>>;
+
+#[derive(Debug)]
+struct Part {
+    extremely_cool_looking: isize,
+    musical: isize,
+    aerodynamic: isize,
+    shiny: isize,
+}
+
+impl Part {
+    fn value(&self) -> isize {
+        self.extremely_cool_looking + self.musical + self.aerodynamic + self.shiny
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{{x={},m={},a={},s={}}}",
+            self.extremely_cool_looking, self.musical, self.aerodynamic, self.shiny
+        )
+    }
+}
+
+fn parse_parts(input: &str) -> Vec<Part> {
+    let mut parts = Vec::default();
+    for part_text in input.lines() {
+        let part_fields = part_text
+            .trim_matches(['{', '}'])
+            .split(',')
+            .map(|s| s[2..].parse::<isize>().unwrap())
+            .collect::<Vec<isize>>();
+        parts.push(Part {
+            extremely_cool_looking: *part_fields.first().unwrap(),
+            musical: *part_fields.get(1).unwrap(),
+            aerodynamic: *part_fields.get(2).unwrap(),
+            shiny: *part_fields.get(3).unwrap(),
+        });
+    }
+    parts
+}
+
+fn parse_workflows(input: &str) -> Workflows<'_> {
+    let mut workflows = new_map();
+    for line in input.lines() {
+        let mut fields = line.split('{');
+        let name = fields.next().unwrap();
+        let rules_text = fields.next().unwrap().trim_end_matches('}');
+
+        let mut rules = Vec::default();
+        for rule_text in rules_text.split(',') {
+            if rule_text.eq("A") {
+                rules.push(Rule {
+                    rule_comparison: None,
+                    action: Accept,
+                });
+            } else if rule_text.eq("R") {
+                rules.push(Rule {
+                    rule_comparison: None,
+                    action: Reject,
+                });
+            } else {
+                if rule_text.contains('<') {
+                    let mut rule_fields = rule_text.split(['<', ':']);
+                    rules.push(Rule {
+                        rule_comparison: Some(RuleComparison {
+                            part_kind: PartKind::new(rule_fields.next().unwrap()),
+                            part_cmp: PartCmp::LessThan,
+                            value: rule_fields.next().unwrap().parse::<isize>().unwrap(),
+                        }),
+                        action: Action::new(rule_fields.next().unwrap()),
+                    });
+                } else if rule_text.contains('>') {
+                    let mut rule_fields = rule_text.split(['>', ':']);
+                    rules.push(Rule {
+                        rule_comparison: Some(RuleComparison {
+                            part_kind: PartKind::new(rule_fields.next().unwrap()),
+                            part_cmp: PartCmp::GreaterThan,
+                            value: rule_fields.next().unwrap().parse::<isize>().unwrap(),
+                        }),
+                        action: Action::new(rule_fields.next().unwrap()),
+                    });
+                } else {
+                    rules.push(Rule {
+                        rule_comparison: None,
+                        action: Action::new(rule_text),
+                    });
+                }
+            }
+        }
+        workflows.insert(name, Workflow { name, rules });
+    }
+    workflows
+}
+
+fn parse_input(input: &str) -> (Workflows<'_>, Vec<Part>) {
+    let mut components = input.split("\n\n");
+    (
+        parse_workflows(components.next().unwrap()),
+        parse_parts(components.next().unwrap()),
+    )
+}
+
+impl RuleComparison {
+    fn matches(&self, part: &Part) -> bool {
+        match (&self.part_kind, &self.part_cmp) {
+            (ExtremelyCoolLooking, LessThan) => part.extremely_cool_looking < self.value,
+            (ExtremelyCoolLooking, GreaterThan) => part.extremely_cool_looking > self.value,
+            (Musical, LessThan) => part.musical < self.value,
+            (Musical, GreaterThan) => part.musical > self.value,
+            (Aerodynamic, LessThan) => part.aerodynamic < self.value,
+            (Aerodynamic, GreaterThan) => part.aerodynamic > self.value,
+            (Shiny, LessThan) => part.shiny < self.value,
+            (Shiny, GreaterThan) => part.shiny > self.value,
+        }
+    }
+}
+
+impl<'a> Workflow<'a> {
+    fn process_part(&self, part: &Part) -> Action<'a> {
+        for rule in &self.rules {
+            if let Some(comparison) = &rule.rule_comparison {
+                if comparison.matches(part) {
+                    return rule.action;
+                }
+            } else {
+                return rule.action;
+            }
+        }
+        panic!("Failed to match any rules...something is wrong in the parser.");
+    }
+}
+
+fn describe<'a>(action: &Action<'a>) -> &'a str {
+    match action {
+        Accept => "A",
+        Reject => "R",
+        SendToWorkflow { workflow } => workflow,
+    }
+}
+
+/// Names of every workflow a part passes through, ending with its verdict.
+fn workflow_path<'a>(workflows: &Workflows<'a>, part: &Part) -> Result<Vec<&'a str>, String> {
+    let mut path = vec!["in"];
+    loop {
+        let name = path.last().unwrap();
+        let workflow = workflows
+            .get(*name)
+            .ok_or_else(|| format!("No workflow named `{name}`"))?;
+        let action = workflow.process_part(part);
+        path.push(describe(&action));
+        if !matches!(action, SendToWorkflow { .. }) {
+            return Ok(path);
+        }
+    }
+}
+
+fn part_1(workflows: &Workflows, parts: &Vec<Part>, trace: &Trace) -> isize {
+    let mut accepted_parts = Vec::new();
+
+    for part in parts {
+        if trace.enabled() {
+            if let Ok(path) = workflow_path(workflows, part) {
+                trace.log(format_args!("{part}: {}", path.join(" → ")));
+            }
+        }
+        let mut current_workflow = "in";
+        loop {
+            let w = workflows
+                .get(current_workflow)
+                .expect("Couldn't find workflow!");
+            match w.process_part(part) {
+                Accept => {
+                    accepted_parts.push(part);
+                    break;
+                }
+                Reject => break,
+                SendToWorkflow { workflow: target } => {
+                    current_workflow = target;
+                }
+            }
+        }
+    }
+    accepted_parts.iter().map(|p| p.value()).sum()
+}
+
+fn part_2(_input: &str) -> Answer {
+    Answer::Unimplemented
+}
+
+struct Repl<'a> {
+    workflows: Workflows<'a>,
+    parts: Vec<Part>,
+}
+
+pub fn repl(input: &str) -> Box<dyn Session + '_> {
+    let (workflows, parts) = parse_input(input);
+    Box::new(Repl { workflows, parts })
+}
+
+/// Reads a part such as `{x=787,m=2655,a=1222,s=2876}` without panicking on typos.
+fn parse_part(input: &str) -> Result<Part, String> {
+    let mut part = Part {
+        extremely_cool_looking: 0,
+        musical: 0,
+        aerodynamic: 0,
+        shiny: 0,
+    };
+    for field in input.trim_matches(['{', '}']).split(',') {
+        let (kind, value) = field
+            .split_once('=')
+            .ok_or_else(|| format!("Expected `kind=value`, got `{field}`"))?;
+        let value = value
+            .parse::<isize>()
+            .map_err(|_| format!("`{value}` is not a rating"))?;
+        match kind {
+            "x" => part.extremely_cool_looking = value,
+            "m" => part.musical = value,
+            "a" => part.aerodynamic = value,
+            "s" => part.shiny = value,
+            _ => return Err(format!("Unknown category `{kind}`")),
+        }
+    }
+    Ok(part)
+}
+
+impl Session for Repl<'_> {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                name: "part",
+                usage: "part <{x=..,m=..,a=..,s=..}>",
+                help: "Follows a part through the workflows from `in`",
+            },
+            Command {
+                name: "workflow",
+                usage: "workflow <name> <part>",
+                help: "Runs `Workflow::process_part` for a single workflow",
+            },
+            Command {
+                name: "workflows",
+                usage: "workflows",
+                help: "Lists the workflow names",
+            },
+            Command {
+                name: "parts",
+                usage: "parts",
+                help: "Shows the path of every part in the input",
+            },
+        ]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "part" => {
+                let part = parse_part(args.first().ok_or("Missing part")?)?;
+                Ok(workflow_path(&self.workflows, &part)?.join(" → "))
+            }
+            "workflow" => {
+                let name = args.first().ok_or("Missing workflow name")?;
+                let workflow = self
+                    .workflows
+                    .get(*name)
+                    .ok_or_else(|| format!("No workflow named `{name}`"))?;
+                let part = parse_part(args.get(1).ok_or("Missing part")?)?;
+                let action = workflow.process_part(&part);
+                Ok(format!("{} → {}", workflow.name, describe(&action)))
+            }
+            "workflows" => {
+                let mut names = self.workflows.values().map(|w| &w.name).collect::<Vec<_>>();
+                names.sort();
+                Ok(format!("{} workflows: {names:?}", names.len()))
+            }
+            "parts" => {
+                let mut lines = Vec::new();
+                for part in &self.parts {
+                    let path = workflow_path(&self.workflows, part)?;
+                    lines.push(format!("{part}: {}", path.join(" → ")));
+                }
+                Ok(lines.join("\n"))
+            }
+            _ => unreachable!("Unknown command"),
+        }
+    }
+}
+
+/// Workflows like `px{a<2006:qkq,m>2090:A,rfg}` starting at `in`, a blank line, then parts like
+/// `{x=787,m=2655,a=1222,s=2876}`.
+pub fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let (workflows, parts) = input.split_once("\n\n").unwrap_or_else(|| {
+        let message = "expected a blank line between the workflows and the parts";
+        violations.push(Violation::new(1, 1, message));
+        (input, "")
+    });
+    let names = workflows
+        .lines()
+        .filter_map(|line| line.split_once('{'))
+        .map(|(name, _)| name)
+        .collect::<FxHashSet<_>>();
+    if !names.contains("in") {
+        violations.push(Violation::new(1, 1, "there is no `in` workflow"));
+    }
+    let is_target = |target: &str| matches!(target, "A" | "R") || names.contains(target);
+    for (y, line) in workflows.lines().enumerate() {
+        let rules = line
+            .split_once('{')
+            .and_then(|(name, rules)| Some((name, rules.strip_suffix('}')?)));
+        let Some((name, rules)) = rules else {
+            violations.push(Violation::new(y + 1, 1, "expected `<name>{<rules>}`"));
+            continue;
+        };
+        let rules = rules.split(',').collect::<Vec<_>>();
+        let mut column = name.len() + 2;
+        for (i, rule) in rules.iter().enumerate() {
+            let valid = if i + 1 == rules.len() {
+                is_target(rule)
+            } else {
+                rule.split_once(':').is_some_and(|(condition, target)| {
+                    // `get` rather than slicing, as the input may not be ASCII.
+                    let category = condition.get(..2).unwrap_or(condition);
+                    let value = condition.get(2..).unwrap_or_default();
+                    matches!(
+                        category.as_bytes(),
+                        [b'x' | b'm' | b'a' | b's', b'<' | b'>']
+                    ) && value.parse::<usize>().is_ok()
+                        && is_target(target)
+                })
+            };
+            if !valid {
+                let message = format!("`{rule}` isn't a rule or a known workflow");
+                violations.push(Violation::new(y + 1, column, message));
+            }
+            column += rule.len() + 1;
+        }
+    }
+    let first_part = workflows.lines().count() + 2;
+    for (y, line) in parts.lines().enumerate() {
+        let ratings = line
+            .strip_prefix('{')
+            .and_then(|l| l.strip_suffix('}'))
+            .map(|l| l.split(',').collect::<Vec<_>>());
+        let valid = ratings.as_ref().is_some_and(|ratings| {
+            ratings.len() == 4
+                && ["x=", "m=", "a=", "s="]
+                    .iter()
+                    .zip(ratings)
+                    .all(|(prefix, rating)| {
+                        rating
+                            .strip_prefix(prefix)
+                            .is_some_and(|value| check_numbers(1, 1, value, false).is_empty())
+                    })
+        });
+        if !valid {
+            let message = format!("expected `{{x=<n>,m=<n>,a=<n>,s=<n>}}`, found `{line}`");
+            violations.push(Violation::new(first_part + y, 1, message));
+        }
+    }
+    violations
+}
+
+/// `size + 1` workflows that only send parts to workflows further down the list, so there are no
+/// loops, followed by `size` parts.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let names = (0..=size)
+        .map(|i| {
+            if i == 0 {
+                String::from("in")
+            } else {
+                let letter = |n: usize| (b'a' + (n % 26) as u8) as char;
+                format!("{}{}x", letter(i / 26), letter(i))
+            }
+        })
+        .collect::<Vec<_>>();
+    let target = |rng: &mut Rng, i: usize| {
+        let next = rng.range(i + 1, size + 2);
+        match names.get(next) {
+            Some(name) if rng.range(0, 1) == 0 => name.clone(),
+            _ => String::from(rng.pick(&["A", "R"])),
+        }
+    };
+    let workflows = (0..=size)
+        .map(|i| {
+            let mut rules = (0..rng.range(1, 3))
+                .map(|_| {
+                    let category = rng.pick(&['x', 'm', 'a', 's']);
+                    let cmp = rng.pick(&['<', '>']);
+                    format!("{category}{cmp}{}:{}", rng.range(1, 4000), target(rng, i))
+                })
+                .collect::<Vec<_>>();
+            rules.push(target(rng, i));
+            format!("{}{{{}}}", names[i], rules.join(","))
+        })
+        .collect::<Vec<_>>();
+    let parts = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [0; 4].map(|_| rng.range(1, 4000));
+            format!("{{x={x},m={m},a={a},s={s}}}")
+        })
+        .collect::<Vec<_>>();
+    format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::normalize_input;
+
+    #[test]
+    fn test_part_1() {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+        let (workflows, parts) = parse_input(input);
+        assert_eq!(part_1(&workflows, &parts, &Trace::default()), 19114);
+    }
+
+    #[test]
+    fn test_part_2() {}
+
+    /// Saved on Windows, with a BOM, CRLF line endings and trailing spaces.
+    #[test]
+    fn test_parse_messy_input() {
+        let input = "\u{feff}in{s<1351:px,R} \r\npx{a<2006:A,R}\r\n\r\n\
+                     {x=787,m=2655,a=1222,s=2876}  \r\n{x=1679,m=44,a=2067,s=496}\r\n\r\n";
+        let input = normalize_input(input);
+        let (workflows, parts) = parse_input(&input);
+        assert_eq!(workflows.len(), 2);
+        assert_eq!(workflows["in"].rules.len(), 2);
+        assert_eq!(workflows["px"].name, "px");
+        let parts = parts.iter().map(Part::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            parts,
+            ["{x=787,m=2655,a=1222,s=2876}", "{x=1679,m=44,a=2067,s=496}"]
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            validate("in{s<1351:px,R}\npx{A}\n\n{x=787,m=2655,a=1222,s=2876}"),
+            []
+        );
+        assert_eq!(
+            validate("in{xé:A,R}\npx{a<12:qq,B}\n\n{x=1,m=2,a=3}"),
+            [
+                Violation::new(1, 4, "`xé:A` isn't a rule or a known workflow"),
+                Violation::new(2, 4, "`a<12:qq` isn't a rule or a known workflow"),
+                Violation::new(2, 12, "`B` isn't a rule or a known workflow"),
+                Violation::new(
+                    4,
+                    1,
+                    "expected `{x=<n>,m=<n>,a=<n>,s=<n>}`, found `{x=1,m=2,a=3}`"
+                ),
+            ]
+        );
+        assert_eq!(
+            validate("in{A}\nGame 1: 3 blue"),
+            [
+                Violation::new(
+                    1,
+                    1,
+                    "expected a blank line between the workflows and the parts"
+                ),
+                Violation::new(2, 1, "expected `<name>{<rules>}`"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use crate::answer::Answer;
+use crate::trace::Trace;
+use crate::DAYS;
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+use std::{slice, str};
+
+/// What `aoc_solve` writes on success. The answer is owned by the library until it's handed back
+/// to `aoc_free`.
+#[repr(C)]
+pub struct AocAnswer {
+    pub answer: *mut c_char,
+    /// Zero for days that don't parse separately.
+    pub parse_nanos: u64,
+    pub part_nanos: u64,
+}
+
+/// Returned by `aoc_solve`, as panics can't cross into C.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Ok,
+    UnknownDay,
+    UnknownPart,
+    InvalidInput,
+    Unimplemented,
+    Panicked,
+}
+
+fn solve(
+    day: u32,
+    part: u32,
+    input: &[u8],
+) -> Result<(Answer, Option<Duration>, Duration), Status> {
+    let day = DAYS
+        .iter()
+        .find(|d| d.day == day as usize)
+        .ok_or(Status::UnknownDay)?;
+    if part != 1 && part != 2 {
+        return Err(Status::UnknownPart);
+    }
+    let input = str::from_utf8(input).map_err(|_| Status::InvalidInput)?;
+    let input = day.prepare_input(input.to_string());
+    let (parse, (answer, duration)) = panic::catch_unwind(AssertUnwindSafe(|| {
+        day.solve_part(&input, part as usize, &Trace::default())
+    }))
+    .map_err(|_| Status::Panicked)?;
+    if !answer.is_implemented() {
+        return Err(Status::Unimplemented);
+    }
+    Ok((answer, parse, duration))
+}
+
+/// Solves one part of a day for `len` bytes of input, writing the answer to `out` and returning
+/// `AOC_OK`, or returning an error code and leaving `out` alone.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` to a writable `AocAnswer`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input: *const u8,
+    len: usize,
+    out: *mut AocAnswer,
+) -> i32 {
+    if input.is_null() || out.is_null() {
+        return Status::InvalidInput as i32;
+    }
+    match solve(day, part, slice::from_raw_parts(input, len)) {
+        Ok((answer, parse, duration)) => {
+            // Answers never contain a nul.
+            let answer = CString::new(answer.to_string()).unwrap_or_default();
+            out.write(AocAnswer {
+                answer: answer.into_raw(),
+                parse_nanos: parse.unwrap_or_default().as_nanos() as u64,
+                part_nanos: duration.as_nanos() as u64,
+            });
+            Status::Ok as i32
+        }
+        Err(status) => status as i32,
+    }
+}
+
+/// Frees an answer returned by `aoc_solve`. Null is ignored.
+///
+/// # Safety
+/// `answer` must come from `aoc_solve` and not have been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free(answer: *mut c_char) {
+    if !answer.is_null() {
+        drop(CString::from_raw(answer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::io::Write;
+    use std::mem::{self, MaybeUninit};
+    use std::process::{Command, Stdio};
+    use std::{env, fs, ptr};
+
+    const HEADER_PATH: &str = "include/aoc.h";
+
+    /// The C header for the functions above. `include/aoc.h` is checked against it, and rewritten
+    /// when `AOC_UPDATE_HEADER` is set.
+    fn header() -> String {
+        let statuses = [
+            (Status::Ok, "AOC_OK", "The answer was written to `out`."),
+            (
+                Status::UnknownDay,
+                "AOC_UNKNOWN_DAY",
+                "There is no solver for the day.",
+            ),
+            (
+                Status::UnknownPart,
+                "AOC_UNKNOWN_PART",
+                "The part isn't 1 or 2.",
+            ),
+            (
+                Status::InvalidInput,
+                "AOC_INVALID_INPUT",
+                "A pointer is null or the input isn't UTF-8.",
+            ),
+            (
+                Status::Unimplemented,
+                "AOC_UNIMPLEMENTED",
+                "The part isn't solved yet.",
+            ),
+            (
+                Status::Panicked,
+                "AOC_PANICKED",
+                "The solver failed on the input.",
+            ),
+        ];
+        let statuses = statuses
+            .iter()
+            .map(|(status, name, doc)| format!("/* {doc} */\n#define {name} {}\n", *status as i32))
+            .collect::<String>();
+        format!(
+            "/* Generated from src/ffi.rs by `AOC_UPDATE_HEADER=1 cargo test`, don't edit. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+{statuses}
+struct AocAnswer {{
+    /* Owned by the library until it's handed to aoc_free. */
+    char *answer;
+    /* Zero for days that don't parse separately. */
+    uint64_t parse_nanos;
+    uint64_t part_nanos;
+}};
+
+/* Solves one part of a day for `len` bytes of input, writing the answer to `out` and returning
+ * AOC_OK, or returning an error code and leaving `out` alone. */
+int32_t aoc_solve(uint32_t day, uint32_t part, const uint8_t *input, size_t len,
+                  struct AocAnswer *out);
+
+/* Frees an answer returned by aoc_solve. Null is ignored. */
+void aoc_free(char *answer);
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif
+"
+        )
+    }
+
+    #[test]
+    fn test_header_is_up_to_date() {
+        if env::var_os("AOC_UPDATE_HEADER").is_some() {
+            fs::write(HEADER_PATH, header()).unwrap();
+        }
+        let header_file = fs::read_to_string(HEADER_PATH).unwrap();
+        assert!(
+            header_file == header(),
+            "{HEADER_PATH} is out of date, run `AOC_UPDATE_HEADER=1 cargo test`"
+        );
+    }
+
+    /// Compiles checks of the header against the layout and codes on the Rust side, so a change
+    /// to `AocAnswer` or `Status` can't get out of step with the C declarations.
+    #[test]
+    fn test_header_matches_rust() {
+        let mut checks = String::from("#include \"aoc.h\"\n");
+        let mut check = |c: String, rust: usize| {
+            checks += &format!("_Static_assert({c} == {rust}, \"{c} is {rust} in Rust\");\n");
+        };
+        check(
+            String::from("sizeof(struct AocAnswer)"),
+            mem::size_of::<AocAnswer>(),
+        );
+        check(
+            String::from("_Alignof(struct AocAnswer)"),
+            mem::align_of::<AocAnswer>(),
+        );
+        fn size<T>(_: fn(&AocAnswer) -> &T) -> usize {
+            mem::size_of::<T>()
+        }
+        for (field, offset, size) in [
+            (
+                "answer",
+                mem::offset_of!(AocAnswer, answer),
+                size(|a| &a.answer),
+            ),
+            (
+                "parse_nanos",
+                mem::offset_of!(AocAnswer, parse_nanos),
+                size(|a| &a.parse_nanos),
+            ),
+            (
+                "part_nanos",
+                mem::offset_of!(AocAnswer, part_nanos),
+                size(|a| &a.part_nanos),
+            ),
+        ] {
+            check(format!("offsetof(struct AocAnswer, {field})"), offset);
+            check(format!("sizeof(((struct AocAnswer *)0)->{field})"), size);
+        }
+        for (name, status) in [
+            ("AOC_OK", Status::Ok),
+            ("AOC_UNKNOWN_DAY", Status::UnknownDay),
+            ("AOC_UNKNOWN_PART", Status::UnknownPart),
+            ("AOC_INVALID_INPUT", Status::InvalidInput),
+            ("AOC_UNIMPLEMENTED", Status::Unimplemented),
+            ("AOC_PANICKED", Status::Panicked),
+        ] {
+            check(String::from(name), status as usize);
+        }
+        // Assigning to pointers of the Rust signatures type-checks the prototypes.
+        checks +=
+            "int32_t (*solve)(uint32_t, uint32_t, const uint8_t *, size_t, struct AocAnswer *) \
+                   = aoc_solve;\nvoid (*free_answer)(char *) = aoc_free;\n";
+
+        let compiler = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+        let mut child = Command::new(&compiler)
+            .args([
+                "-std=c11",
+                "-fsyntax-only",
+                "-include",
+                "stddef.h",
+                "-I",
+                "include",
+            ])
+            .args(["-x", "c", "-"])
+            .stdin(Stdio::piped())
+            .spawn()
+            .unwrap_or_else(|e| panic!("Couldn't run the C compiler `{compiler}`: {e}"));
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(checks.as_bytes())
+            .unwrap();
+        assert!(
+            child.wait().unwrap().success(),
+            "{HEADER_PATH} doesn't match:\n{checks}"
+        );
+    }
+
+    fn call(day: u32, part: u32, input: &[u8]) -> Result<String, i32> {
+        let mut out = MaybeUninit::<AocAnswer>::uninit();
+        unsafe {
+            match aoc_solve(day, part, input.as_ptr(), input.len(), out.as_mut_ptr()) {
+                0 => {
+                    let out = out.assume_init();
+                    let answer = CStr::from_ptr(out.answer).to_str().unwrap().to_string();
+                    aoc_free(out.answer);
+                    Ok(answer)
+                }
+                status => Err(status),
+            }
+        }
+    }
+
+    #[test]
+    fn test_aoc_solve() {
+        let input = b"Time:      7  15   30\r\nDistance:  9  40  200\r\n";
+        assert_eq!(call(6, 1, input), Ok(String::from("288")));
+        assert_eq!(call(6, 2, input), Ok(String::from("71503")));
+        assert_eq!(call(12, 1, input), Err(Status::UnknownDay as i32));
+        assert_eq!(call(6, 3, input), Err(Status::UnknownPart as i32));
+        assert_eq!(call(6, 1, b"\xff"), Err(Status::InvalidInput as i32));
+        assert_eq!(
+            call(19, 2, b"in{x>1:A,R}\n\n{x=1,m=1,a=1,s=1}"),
+            Err(Status::Unimplemented as i32)
+        );
+        let null = unsafe { aoc_solve(6, 1, ptr::null(), 0, ptr::null_mut()) };
+        assert_eq!(null, Status::InvalidInput as i32);
+        unsafe { aoc_free(ptr::null_mut()) };
+    }
+}
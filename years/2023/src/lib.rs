@@ -0,0 +1,194 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day14;
+mod day15;
+mod day19;
+mod ffi;
+
+// The days reach the harness through `crate::`, like modules of this crate.
+#[cfg(test)]
+use aoc_harness::differential;
+use aoc_harness::{
+    answer, generator, helpers, repl, trace, validate, variants, DayResult, PartResult,
+};
+use aoc_harness::{Day, Year};
+use colored::Color::{Green, Red};
+
+pub const YEAR: Year = Year {
+    year: 2023,
+    days: &DAYS,
+};
+
+const DAYS: [Day; 14] = [
+    Day::new(2023, 1, day01::run, Red)
+        .with_run_part(day01::run_part)
+        .with_generator(day01::generate)
+        .with_validator(day01::validate)
+        .parallel(),
+    Day::new(2023, 2, day02::run, Green)
+        .with_run_part(day02::run_part)
+        .with_generator(day02::generate)
+        .with_validator(day02::validate),
+    Day::new(2023, 3, day03::run, Red)
+        .with_run_part(day03::run_part)
+        .with_generator(day03::generate)
+        .with_validator(day03::validate),
+    Day::new(2023, 4, day04::run, Green)
+        .with_run_part(day04::run_part)
+        .with_generator(day04::generate)
+        .with_validator(day04::validate),
+    Day::new(2023, 5, day05::run, Red)
+        .with_run_part(day05::run_part)
+        .with_generator(day05::generate)
+        .with_validator(day05::validate)
+        .with_repl(day05::repl)
+        .with_variants(day05::VARIANTS)
+        .parallel(),
+    Day::new(2023, 6, day06::run, Green)
+        .with_run_part(day06::run_part)
+        .with_generator(day06::generate)
+        .with_validator(day06::validate),
+    Day::new(2023, 7, day07::run, Red)
+        .with_run_part(day07::run_part)
+        .with_generator(day07::generate)
+        .with_validator(day07::validate)
+        .with_repl(day07::repl),
+    Day::new(2023, 8, day08::run, Green)
+        .with_run_part(day08::run_part)
+        .with_generator(day08::generate)
+        .with_validator(day08::validate)
+        .parallel(),
+    Day::new(2023, 9, day09::run, Red)
+        .with_run_part(day09::run_part)
+        .with_generator(day09::generate)
+        .with_validator(day09::validate),
+    Day::new(2023, 10, day10::run, Green)
+        .with_run_part(day10::run_part)
+        .with_generator(day10::generate)
+        .with_validator(day10::validate),
+    Day::new(2023, 11, day11::run, Red)
+        .with_run_part(day11::run_part)
+        .with_generator(day11::generate)
+        .with_validator(day11::validate),
+    Day::new(2023, 14, day14::run, Green)
+        .with_run_part(day14::run_part)
+        .with_generator(day14::generate)
+        .with_validator(day14::validate),
+    Day::new(2023, 15, day15::run, Red)
+        .with_run_part(day15::run_part)
+        .with_generator(day15::generate)
+        .with_validator(day15::validate),
+    Day::new(2023, 19, day19::run, Red)
+        .with_run_part(day19::run_part)
+        .with_generator(day19::generate)
+        .with_validator(day19::validate)
+        .with_repl(day19::repl),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use answer::Answer;
+    use generator::Rng;
+    use std::{fs, panic};
+    use trace::Trace;
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for day in DAYS.iter().filter(|d| d.enabled) {
+            let generate = day.generate.unwrap();
+            for size in 1..=6 {
+                for seed in 0..10 {
+                    let input = generate(&mut Rng::new(seed), size);
+                    let result = panic::catch_unwind(|| (day.run)(&input, &Trace::default()));
+                    assert!(result.is_ok(), "Day {} failed on:\n{input}", day.day);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_part_agrees_with_run() {
+        for day in DAYS.iter().filter(|d| d.enabled) {
+            let generate = day.generate.unwrap();
+            for seed in 0..5 {
+                let input = generate(&mut Rng::new(seed), 5);
+                let (_, p1, p2) = (day.run)(&input, &Trace::default());
+                for (part, expected) in [(1, p1.0), (2, p2.0)] {
+                    let (_, (answer, _)) = day.solve_part(&input, part, &Trace::default());
+                    assert_eq!(answer, expected, "Day {} part {part} on:\n{input}", day.day);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_are_valid() {
+        for day in &DAYS {
+            let (Some(generate), Some(validate)) = (day.generate, day.validate) else {
+                continue;
+            };
+            for size in 1..=6 {
+                for seed in 0..10 {
+                    let input = day.prepare_input(generate(&mut Rng::new(seed), size));
+                    let violations = validate(&input);
+                    assert!(
+                        violations.is_empty(),
+                        "Day {}: {violations:?} in:\n{input}",
+                        day.day
+                    );
+                }
+            }
+        }
+    }
+
+    /// Validators report bad input, so they must not panic on it, not even on multi-byte
+    /// characters where a slice would split them.
+    #[test]
+    fn test_validators_never_panic() {
+        for day in &DAYS {
+            let (Some(generate), Some(validate)) = (day.generate, day.validate) else {
+                continue;
+            };
+            for seed in 0..3 {
+                let input = generate(&mut Rng::new(seed), 2);
+                for (i, _) in input.char_indices() {
+                    let input = format!("{}é{}", &input[..i], &input[i + 1..]);
+                    let result = panic::catch_unwind(|| validate(&input));
+                    assert!(result.is_ok(), "Day {} panicked on:\n{input}", day.day);
+                }
+            }
+        }
+    }
+
+    /// Checks the examples `aoc examples` saved from the puzzle pages.
+    #[test]
+    fn test_fixtures() {
+        for day in &DAYS {
+            let dir = format!("fixtures/day_{:0>2}", day.day);
+            for part in [1, 2] {
+                let (Ok(input), Ok(expected)) = (
+                    fs::read_to_string(format!("{dir}/part_{part}.txt")),
+                    fs::read_to_string(format!("{dir}/part_{part}_answer.txt")),
+                ) else {
+                    continue;
+                };
+                let (_, p1, p2) = (day.run)(&day.prepare_input(input), &Trace::default());
+                let answer = if part == 1 { p1.0 } else { p2.0 };
+                let Ok(expected) = expected.trim().parse::<Answer>();
+                if answer.is_implemented() {
+                    assert_eq!(answer, expected, "Day {} part {part}", day.day);
+                }
+            }
+        }
+    }
+}
@@ -1,27 +1,29 @@
+use crate::answer::Answer;
+use crate::trace::Trace;
 use crate::DayResult;
 use std::time::Instant;
 
-pub fn run(input: &str) -> DayResult {
+pub fn run(input: &str, _trace: &Trace) -> DayResult {
     let start = Instant::now();
     let parse_duration = start.elapsed();
 
     let start = Instant::now();
-    let p1 = part_1(&input).to_string();
+    let p1 = part_1(&input);
     let p1_duration = start.elapsed();
 
     let start = Instant::now();
-    let p2 = part_2(&input).to_string();
+    let p2 = part_2(&input);
     let p2_duration = start.elapsed();
     // (Some(parse_duration), (p1, p1_duration), (p2, p2_duration))
     (None, (p1, p1_duration), (p2, p2_duration))
 }
 
-fn part_1(input: &str) -> usize {
-    0
+fn part_1(input: &str) -> Answer {
+    Answer::Unimplemented
 }
 
-fn part_2(input: &str) -> usize {
-    0
+fn part_2(input: &str) -> Answer {
+    Answer::Unimplemented
 }
 
 
@@ -0,0 +1,52 @@
+/* Calls the solvers through the C API. Build the library first, then from the repository root:
+ *
+ *   cargo build
+ *   cc years/2023/tests/ffi.c -Iyears/2023/include -Ltarget/debug -laoc_2023 -o target/ffi_test
+ *   LD_LIBRARY_PATH=target/debug target/ffi_test
+ */
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+static void expect_answer(uint32_t day, uint32_t part, const char *input, const char *expected) {
+    struct AocAnswer out;
+    int32_t status = aoc_solve(day, part, (const uint8_t *)input, strlen(input), &out);
+    if (status != AOC_OK) {
+        printf("FAIL day %u part %u: error %d\n", day, part, status);
+        failures++;
+        return;
+    }
+    if (strcmp(out.answer, expected) != 0) {
+        printf("FAIL day %u part %u: got %s, expected %s\n", day, part, out.answer, expected);
+        failures++;
+    } else {
+        printf("ok   day %u part %u: %s (%llu ns)\n", day, part, out.answer,
+               (unsigned long long)(out.parse_nanos + out.part_nanos));
+    }
+    aoc_free(out.answer);
+}
+
+static void expect_error(uint32_t day, uint32_t part, const char *input, int32_t expected) {
+    struct AocAnswer out;
+    int32_t status = aoc_solve(day, part, (const uint8_t *)input, strlen(input), &out);
+    if (status != expected) {
+        printf("FAIL day %u part %u: status %d, expected %d\n", day, part, status, expected);
+        failures++;
+    } else {
+        printf("ok   day %u part %u: error %d\n", day, part, status);
+    }
+}
+
+int main(void) {
+    const char *day_6 = "Time:      7  15   30\nDistance:  9  40  200\n";
+    expect_answer(6, 1, day_6, "288");
+    expect_answer(6, 2, day_6, "71503");
+    expect_error(12, 1, day_6, AOC_UNKNOWN_DAY);
+    expect_error(6, 3, day_6, AOC_UNKNOWN_PART);
+    expect_error(6, 1, "\xff", AOC_INVALID_INPUT);
+    expect_error(6, 2, "Time: 7\n", AOC_PANICKED);
+    return failures == 0 ? 0 : 1;
+}